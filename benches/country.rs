@@ -22,3 +22,93 @@ pub fn parse_name(b : &mut Bencher) {
         let _s = c.name();
     });
 }
+
+#[bench]
+pub fn parse_alpha3(b : &mut Bencher) {
+
+    b.iter(|| {
+        let _c : Country = test::black_box("POL").parse().unwrap();
+    });
+}
+
+#[bench]
+pub fn parse_numeric(b : &mut Bencher) {
+
+    b.iter(|| {
+        let _c : Country = test::black_box("616").parse().unwrap();
+    });
+}
+
+#[bench]
+pub fn from_name(b : &mut Bencher) {
+
+    b.iter(|| {
+        let _c = Country::from_name(test::black_box("United Kingdom of Great Britain and Northern Ireland")).unwrap();
+    });
+}
+
+#[bench]
+pub fn from_name_miss(b : &mut Bencher) {
+
+    b.iter(|| {
+        let _c = Country::from_name(test::black_box("Atlantis"));
+    });
+}
+
+#[bench]
+pub fn parse_any(b : &mut Bencher) {
+
+    b.iter(|| {
+        let _c = Country::parse_any(test::black_box("Poland")).unwrap();
+    });
+}
+
+fn column() -> Vec<u8> {
+    let codes = ["PL", "DEU", "276", "FR", "GBR", "US", "IT", "JP"];
+    let mut data = Vec::new();
+    for i in 0..10_000 {
+        data.extend_from_slice(codes[i % codes.len()].as_bytes());
+        data.push(b'\n');
+    }
+    data
+}
+
+#[bench]
+pub fn column_from_str(b : &mut Bencher) {
+    let data = column();
+    b.bytes = data.len() as u64;
+
+    b.iter(|| {
+        let text = std::str::from_utf8(&data).unwrap();
+        text.lines().filter(|s| s.parse::<Country>().is_ok()).count()
+    });
+}
+
+#[bench]
+pub fn column_parse_many(b : &mut Bencher) {
+    let data = column();
+    let fields: Vec<&[u8]> = data.split(|&b| b == b'\n').collect();
+    b.bytes = data.len() as u64;
+
+    b.iter(|| {
+        Country::parse_many(&fields).iter().filter(|r| r.is_ok()).count()
+    });
+}
+
+#[bench]
+pub fn column_parse_column(b : &mut Bencher) {
+    let data = column();
+    b.bytes = data.len() as u64;
+
+    b.iter(|| {
+        Country::parse_column(&data, b'\n').filter(|r| r.is_ok()).count()
+    });
+}
+
+#[bench]
+pub fn search(b : &mut Bencher) {
+
+    b.iter(|| {
+        let _r = Country::search(test::black_box("Phillipines"));
+    });
+}
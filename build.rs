@@ -0,0 +1,440 @@
+// Generates the `Country` enum and all of its lookup tables from the
+// tab-separated files in `data/`, so that every representation of a country
+// is derived from a single record.
+
+use std::collections::{ BTreeMap, BTreeSet };
+use std::env;
+use std::fmt::Write as FmtWrite;
+use std::fs;
+use std::path::Path;
+
+#[path = "src/hash.rs"]
+mod hash;
+#[path = "src/normalize.rs"]
+mod normalize;
+
+struct Record {
+    alpha2: String,
+    alpha3: String,
+    numeric: u16,
+    name: String,
+    official_name: String,
+}
+
+fn rows(path: &str) -> Vec<(usize, Vec<String>)> {
+    println!("cargo:rerun-if-changed={}", path);
+
+    let text = fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("{}: {}", path, e));
+
+    text.lines()
+        .enumerate()
+        .filter(|&(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(n, line)| (n + 1, line.split('\t').map(|f| f.trim().to_string()).collect()))
+        .collect()
+}
+
+fn is_code(s: &str, len: usize) -> bool {
+    s.len() == len && s.bytes().all(|b| b.is_ascii_uppercase())
+}
+
+fn load_countries(path: &str) -> Vec<Record> {
+    let mut records = Vec::new();
+    let mut alpha3s = BTreeSet::new();
+    let mut numerics = BTreeSet::new();
+
+    for (line, fields) in rows(path) {
+        if fields.len() < 4 || fields.len() > 5 {
+            panic!("{}:{}: expected 4 or 5 fields, found {}", path, line, fields.len());
+        }
+        let optional = |i: usize| match fields.get(i) {
+            Some(name) if !name.is_empty() => name.clone(),
+            _ => fields[3].clone(),
+        };
+
+        let record = Record {
+            alpha2: fields[0].clone(),
+            alpha3: fields[1].clone(),
+            numeric: fields[2].parse()
+                .unwrap_or_else(|_| panic!("{}:{}: invalid numeric code {:?}", path, line, fields[2])),
+            name: fields[3].clone(),
+            official_name: optional(4),
+        };
+
+        if !is_code(&record.alpha2, 2) {
+            panic!("{}:{}: invalid alpha-2 code {:?}", path, line, record.alpha2);
+        }
+        if !is_code(&record.alpha3, 3) {
+            panic!("{}:{}: invalid alpha-3 code {:?}", path, line, record.alpha3);
+        }
+        if fields[2].len() != 3 || record.numeric == 0 {
+            panic!("{}:{}: invalid numeric code {:?}", path, line, fields[2]);
+        }
+        for name in &[&record.name, &record.official_name] {
+            if name.is_empty() || name.contains(&['"', '\\', '['][..]) {
+                panic!("{}:{}: invalid name {:?}", path, line, name);
+            }
+        }
+        if let Some(prev) = records.last().map(|r: &Record| &r.alpha2) {
+            if *prev >= record.alpha2 {
+                panic!("{}:{}: {} is not sorted after {}", path, line, record.alpha2, prev);
+            }
+        }
+        if !alpha3s.insert(record.alpha3.clone()) {
+            panic!("{}:{}: duplicate alpha-3 code {}", path, line, record.alpha3);
+        }
+        if !numerics.insert(record.numeric) {
+            panic!("{}:{}: duplicate numeric code {:03}", path, line, record.numeric);
+        }
+
+        records.push(record);
+    }
+
+    records
+}
+
+fn load_names(path: &str, countries: &[Record]) -> Vec<(String, String)> {
+    let mut seen = BTreeSet::new();
+    rows(path).into_iter()
+        .map(|(line, fields)| {
+            if fields.len() != 2 {
+                panic!("{}:{}: expected 2 fields, found {}", path, line, fields.len());
+            }
+            if !countries.iter().any(|r| r.alpha2 == fields[0]) {
+                panic!("{}:{}: unknown alpha-2 code {:?}", path, line, fields[0]);
+            }
+            if fields[1].is_empty() || fields[1].contains(&['"', '\\', '['][..]) {
+                panic!("{}:{}: invalid name {:?}", path, line, fields[1]);
+            }
+            if !seen.insert(fields[1].clone()) {
+                panic!("{}:{}: duplicate name {:?}", path, line, fields[1]);
+            }
+            (fields[0].clone(), fields[1].clone())
+        })
+        .collect()
+}
+
+fn load_reserved(path: &str, countries: &[Record]) -> Vec<(String, &'static str, String)> {
+    let mut reserved: Vec<(String, &'static str, String)> = Vec::new();
+
+    for (line, fields) in rows(path) {
+        if fields.len() != 3 {
+            panic!("{}:{}: expected 3 fields, found {}", path, line, fields.len());
+        }
+        if !is_code(&fields[0], 2) || countries.iter().any(|r| r.alpha2 == fields[0]) {
+            panic!("{}:{}: invalid or assigned alpha-2 code {:?}", path, line, fields[0]);
+        }
+        if let Some((prev, _, _)) = reserved.last() {
+            if *prev >= fields[0] {
+                panic!("{}:{}: {} is not sorted after {}", path, line, fields[0], prev);
+            }
+        }
+
+        let status = match &fields[1][..] {
+            "exceptional" => "ExceptionallyReserved",
+            "transitional" => "TransitionallyReserved",
+            "indeterminate" => "IndeterminatelyReserved",
+            "former" => "FormerlyUsed",
+            "user" => "UserAssigned",
+            other => panic!("{}:{}: unknown status {:?}", path, line, other),
+        };
+
+        reserved.push((fields[0].clone(), status, fields[2].clone()));
+    }
+
+    reserved
+}
+
+fn load_replacements(path: &str, countries: &[Record]) -> Vec<(String, String)> {
+    let mut replacements: Vec<(String, String)> = Vec::new();
+
+    for (line, fields) in rows(path) {
+        if fields.len() != 2 {
+            panic!("{}:{}: expected 2 fields, found {}", path, line, fields.len());
+        }
+        if !is_code(&fields[0], 2) || countries.iter().any(|r| r.alpha2 == fields[0]) {
+            panic!("{}:{}: invalid or assigned alpha-2 code {:?}", path, line, fields[0]);
+        }
+        if !countries.iter().any(|r| r.alpha2 == fields[1]) {
+            panic!("{}:{}: unknown alpha-2 code {:?}", path, line, fields[1]);
+        }
+        if let Some((prev, _)) = replacements.last() {
+            if *prev >= fields[0] {
+                panic!("{}:{}: {} is not sorted after {}", path, line, fields[0], prev);
+            }
+        }
+
+        replacements.push((fields[0].clone(), fields[1].clone()));
+    }
+
+    replacements
+}
+
+/// Lays out a perfect-hash index over `keys` using hash and displace: keys
+/// are grouped into buckets, and each bucket, largest first, gets the
+/// smallest displacement that moves all of its keys into free slots.
+/// Returns the displacement per bucket and the key index per slot, with
+/// `u16::MAX` for empty slots.
+fn perfect_hash(keys: &[&str]) -> (Vec<u16>, Vec<u16>) {
+    let buckets = keys.len().div_ceil(4);
+    let slots = (keys.len() * 2).next_power_of_two();
+
+    let hashes: Vec<u64> = keys.iter().map(|k| hash::hash(k.as_bytes())).collect();
+    let mut by_bucket = vec![Vec::new(); buckets];
+    for (i, &h) in hashes.iter().enumerate() {
+        by_bucket[hash::bucket(h, buckets)].push(i);
+    }
+
+    let mut order: Vec<usize> = (0..buckets).collect();
+    order.sort_by_key(|&b| std::cmp::Reverse(by_bucket[b].len()));
+
+    let mut displacements = vec![0u16; buckets];
+    let mut table = vec![u16::MAX; slots];
+    for b in order {
+        let members = &by_bucket[b];
+        let d = (0..=u16::MAX)
+            .find(|&d| {
+                let mut taken = BTreeSet::new();
+                members.iter().all(|&i| {
+                    let slot = hash::slot(hashes[i], d, slots);
+                    table[slot] == u16::MAX && taken.insert(slot)
+                })
+            })
+            .expect("no displacement found for the perfect-hash index");
+
+        displacements[b] = d;
+        for &i in members {
+            table[hash::slot(hashes[i], d, slots)] = i as u16;
+        }
+    }
+
+    (displacements, table)
+}
+
+fn feature(name: &str) -> bool {
+    env::var_os(format!("CARGO_FEATURE_{}", name.to_uppercase().replace('-', "_"))).is_some()
+}
+
+struct Endonym {
+    alpha2: String,
+    language: String,
+    script: String,
+    name: String,
+}
+
+fn load_endonyms(path: &str, countries: &[Record]) -> Vec<Endonym> {
+    let mut endonyms: Vec<Endonym> = Vec::new();
+
+    for (line, fields) in rows(path) {
+        if fields.len() != 4 {
+            panic!("{}:{}: expected 4 fields, found {}", path, line, fields.len());
+        }
+
+        let endonym = Endonym {
+            alpha2: fields[0].clone(),
+            language: fields[1].clone(),
+            script: fields[2].clone(),
+            name: fields[3].clone(),
+        };
+
+        if !countries.iter().any(|r| r.alpha2 == endonym.alpha2) {
+            panic!("{}:{}: unknown alpha-2 code {:?}", path, line, endonym.alpha2);
+        }
+        if !(2..=3).contains(&endonym.language.len()) || !endonym.language.bytes().all(|b| b.is_ascii_lowercase()) {
+            panic!("{}:{}: invalid language code {:?}", path, line, endonym.language);
+        }
+        let script = endonym.script.as_bytes();
+        if script.len() != 4 || !script[0].is_ascii_uppercase() || !script[1..].iter().all(|b| b.is_ascii_lowercase()) {
+            panic!("{}:{}: invalid script code {:?}", path, line, endonym.script);
+        }
+        if endonym.name.is_empty() || endonym.name.contains(&['"', '\\'][..]) {
+            panic!("{}:{}: invalid name {:?}", path, line, endonym.name);
+        }
+        if let Some(prev) = endonyms.last() {
+            if prev.alpha2 > endonym.alpha2 {
+                panic!("{}:{}: {} is not sorted after {}", path, line, endonym.alpha2, prev.alpha2);
+            }
+        }
+
+        endonyms.push(endonym);
+    }
+
+    endonyms
+}
+
+fn main() {
+    let (names, alpha3, numeric) = (feature("names"), feature("alpha3"), feature("numeric"));
+
+    let countries = load_countries("data/countries.tsv");
+    let other_names = load_names("data/other_names.tsv", &countries);
+    let aliases = load_names("data/aliases.tsv", &countries);
+    let reserved = load_reserved("data/reserved.tsv", &countries);
+    let replacements = load_replacements("data/replacements.tsv", &countries);
+
+    let mut out = String::new();
+
+    out.push_str("#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]\n");
+    out.push_str("pub enum Country {\n    Unspecified = 0,\n");
+    for r in &countries {
+        writeln!(out, "    {} = {},", r.alpha2, r.numeric).unwrap();
+    }
+    out.push_str("}\n\n");
+
+    out.push_str("impl Country {\n");
+    out.push_str("    /// Every country except `Unspecified`, ordered by alpha-2 code.\n");
+    out.push_str("    pub const ALL: &'static [Country] = &[\n");
+    for r in &countries {
+        writeln!(out, "        Country::{},", r.alpha2).unwrap();
+    }
+    out.push_str("    ];\n\n");
+    out.push_str("    /// The position of this country in `Country::ALL`, or `None` for `Unspecified`.\n");
+    out.push_str("    pub const fn index(self) -> Option<usize> {\n        Some(match self {\n");
+    out.push_str("            Country::Unspecified => return None,\n");
+    for (i, r) in countries.iter().enumerate() {
+        writeln!(out, "            Country::{} => {},", r.alpha2, i).unwrap();
+    }
+    out.push_str("        })\n    }\n}\n\n");
+
+    // One table per field, so that disabled fields are not compiled in.
+    let mut field = |table: &str, enabled: bool, value: &dyn Fn(&Record) -> String| {
+        if enabled {
+            writeln!(out, "static {}: [&str; {}] = [", table, countries.len()).unwrap();
+            for r in &countries {
+                writeln!(out, "    \"{}\",", value(r)).unwrap();
+            }
+            out.push_str("];\n\n");
+        }
+    };
+    field("ALPHA2_STRS", true, &|r| r.alpha2.clone());
+    field("ALPHA3_STRS", alpha3, &|r| r.alpha3.clone());
+    field("NUMERIC_STRS", numeric, &|r| format!("{:03}", r.numeric));
+    field("NAMES", names, &|r| r.name.clone());
+    field("OFFICIAL_NAMES", names, &|r| r.official_name.clone());
+
+    // Localised names, with "" where a language has no entry.
+    for &(lang, table) in &[("fr", "FRENCH_NAMES"), ("de", "GERMAN_NAMES"), ("es", "SPANISH_NAMES")] {
+        if feature(&format!("names-{}", lang)) {
+            let localised: BTreeMap<String, String> =
+                load_names(&format!("data/names/{}.tsv", lang), &countries).into_iter().collect();
+            field(table, true, &|r| localised.get(&r.alpha2).cloned().unwrap_or_default());
+        }
+    }
+
+    if feature("endonyms") {
+        let endonyms = load_endonyms("data/endonyms.tsv", &countries);
+        writeln!(out, "static ENDONYMS: [endonym::Endonym; {}] = [", endonyms.len()).unwrap();
+        for e in &endonyms {
+            writeln!(out, "    endonym::Endonym {{ name: \"{}\", language: \"{}\", script: \"{}\" }},",
+                     e.name, e.language, e.script).unwrap();
+        }
+        out.push_str("];\n\n");
+
+        // The range of `ENDONYMS` belonging to each country.
+        writeln!(out, "static ENDONYM_RANGES: [(u16, u16); {}] = [", countries.len()).unwrap();
+        for r in &countries {
+            let start = endonyms.iter().position(|e| e.alpha2 >= r.alpha2).unwrap_or(endonyms.len());
+            let end = start + endonyms[start..].iter().take_while(|e| e.alpha2 == r.alpha2).count();
+            writeln!(out, "    ({}, {}),", start, end).unwrap();
+        }
+        out.push_str("];\n\n");
+    }
+
+    if feature("data-table") {
+        writeln!(out, "static COUNTRY_INFO: [data::CountryInfo; {}] = [", countries.len()).unwrap();
+        for r in &countries {
+            writeln!(out, "    data::CountryInfo {{ country: Country::{0}, alpha2: \"{0}\", alpha3: \"{1}\", numeric: {2}, name: \"{3}\" }},",
+                     r.alpha2, r.alpha3, r.numeric, r.name).unwrap();
+        }
+        out.push_str("];\n\n");
+    }
+
+    if numeric {
+        let numeric: BTreeMap<u16, &str> = countries.iter().map(|r| (r.numeric, &r.alpha2[..])).collect();
+        out.push_str("const COUNTRY_NUMERIC_SEARCH_TABLE : &[(u16, Country)] = &[\n");
+        for (n, a2) in numeric {
+            writeln!(out, "    ({}, Country::{}),", n, a2).unwrap();
+        }
+        out.push_str("];\n\n");
+    }
+
+    // Every code and name form shares one index. Codes and names never
+    // collide: names are longer than three characters. Aliases may be short,
+    // so they are checked against the codes like any other key, and only
+    // allowed to match a code of their own country.
+    let mut index: BTreeMap<String, (&str, &str, bool)> = BTreeMap::new();
+    let all_keys = countries.iter()
+        .flat_map(|r| vec![
+            (r.alpha2.clone(), &r.alpha2, "Alpha2"),
+            (r.alpha3.clone(), &r.alpha2, "Alpha3"),
+            (format!("{:03}", r.numeric), &r.alpha2, "Numeric"),
+            (r.name.clone(), &r.alpha2, "Name"),
+            (r.official_name.clone(), &r.alpha2, "Name"),
+        ])
+        .chain(other_names.iter().map(|(a2, name)| (name.clone(), a2, "Name")))
+        .chain(aliases.iter().map(|(a2, alias)| (alias.clone(), a2, "Alias")))
+        .filter(|&(_, _, format)| match format {
+            "Alpha3" => alpha3,
+            "Numeric" => numeric,
+            "Name" | "Alias" => names,
+            _ => true,
+        });
+    for (key, a2, format) in all_keys {
+        if format == "Name" && key.len() <= 3 {
+            panic!("name {:?} of {} could be mistaken for a code", key, a2);
+        }
+        let alias = format == "Alias";
+        let format = if alias { "Name" } else { format };
+        match index.get_mut(&key) {
+            None => { index.insert(key, (&a2[..], format, alias)); }
+            Some(&mut (prev, _, _)) if prev != a2 => panic!("{:?} is used by both {} and {}", key, prev, a2),
+            // An alias that is also one of the country's codes, e.g. "USA".
+            Some(entry) if alias && entry.1 != "Name" => entry.2 = true,
+            Some(entry) if alias || entry.2 || entry.1 != format =>
+                panic!("{:?} is used twice by {}", key, a2),
+            Some(_) => {}
+        }
+    }
+
+    let keys: Vec<&str> = index.keys().map(|k| &k[..]).collect();
+    let (displacements, slots) = perfect_hash(&keys);
+
+    // The last field marks aliases, which only `from_name_or_alias` accepts.
+    // Names that are aliases are left out of every other lookup.
+    out.push_str("const INDEX_ENTRIES : &[(&str, Country, CountryFormat, bool)] = &[\n");
+    for (key, &(a2, format, alias)) in &index {
+        writeln!(out, "    (\"{}\", Country::{}, CountryFormat::{}, {}),", key, a2, format, alias).unwrap();
+    }
+    out.push_str("];\n\n");
+
+    writeln!(out, "const INDEX_DISPLACEMENTS : &[u16] = &{:?};\n", displacements).unwrap();
+    writeln!(out, "const INDEX_SLOTS : &[u16] = &{:?};\n", slots).unwrap();
+
+    // Names and aliases as `Country::search` compares them, normalised once
+    // here rather than on every search.
+    if names && feature("std") {
+        let search_keys: BTreeSet<(String, &str)> = index.iter()
+            .filter(|&(_, &(_, format, alias))| format == "Name" || alias)
+            .map(|(key, &(a2, _, _))| (normalize::normalize(key), a2))
+            .collect();
+        out.push_str("const SEARCH_KEYS : &[(&str, Country)] = &[\n");
+        for (key, a2) in &search_keys {
+            writeln!(out, "    (\"{}\", Country::{}),", key, a2).unwrap();
+        }
+        out.push_str("];\n\n");
+    }
+
+    out.push_str("const RESERVED_CODE_SEARCH_TABLE : &[(&str, status::CodeStatus, &str)] = &[\n");
+    for (a2, status, name) in &reserved {
+        writeln!(out, "    (\"{}\", status::CodeStatus::{}, \"{}\"),", a2, status, name).unwrap();
+    }
+    out.push_str("];\n\n");
+
+    out.push_str("const REPLACEMENT_CODE_SEARCH_TABLE : &[(&str, Country)] = &[\n");
+    for (code, replacement) in &replacements {
+        writeln!(out, "    (\"{}\", Country::{}),", code, replacement).unwrap();
+    }
+    out.push_str("];\n");
+
+    let dest = Path::new(&env::var("OUT_DIR").unwrap()).join("countries.rs");
+    fs::write(dest, out).unwrap();
+}
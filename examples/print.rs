@@ -1,13 +1,7 @@
 extern crate iso_country as country;
 
 pub fn main() {
-    // use std::ascii::AsciiExt;
-    let mut countries = country::data::all();
-    countries.sort_by_key(|country| country.alpha2);
-
-    for country in countries {
-        // let num : usize = country.num.parse().unwrap();
-        // println!("(\"{}\",  Country::{}),", country.alpha2, country.alpha2);
-        println!("\"{}\" => {},", country.name, country.alpha2);
+    for info in country::data::COUNTRIES {
+        println!("\"{}\" => {},", info.name, info.alpha2);
     }
 }
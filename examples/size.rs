@@ -0,0 +1,30 @@
+// Parses a code from the command line and prints what the enabled features
+// know about it. Used to measure the binary size cost of each feature:
+//
+//     cargo build --release --example size --no-default-features --features names
+extern crate iso_country;
+
+use iso_country::Country;
+
+fn main() {
+    let code = std::env::args().nth(1).unwrap_or_default();
+    let country: Country = match code.parse() {
+        Ok(country) => country,
+        Err(err) => {
+            eprintln!("{}", err);
+            std::process::exit(1);
+        }
+    };
+
+    println!("alpha-2: {}", country.alpha2());
+    #[cfg(feature = "alpha3")]
+    println!("alpha-3: {}", country.alpha3());
+    #[cfg(feature = "numeric")]
+    println!("numeric: {}", country.numeric_str());
+    #[cfg(feature = "names")]
+    for &lang in iso_country::Language::ALL {
+        println!("name {}: {}", lang.code(), country.name_in(lang));
+    }
+    #[cfg(feature = "data-table")]
+    println!("info:    {:?}", country.info());
+}
@@ -0,0 +1,51 @@
+use core::iter;
+
+use error::CountryParseError;
+use Country;
+
+/// Iterator over the countries in a column of codes, see `Country::parse_column`.
+#[derive(Clone, Debug)]
+pub struct ParseColumn<'a> {
+    rest: &'a [u8],
+    delimiter: u8,
+}
+
+impl<'a> ParseColumn<'a> {
+    pub(crate) fn new(data: &'a [u8], delimiter: u8) -> ParseColumn<'a> {
+        ParseColumn { rest: data, delimiter }
+    }
+}
+
+impl<'a> Iterator for ParseColumn<'a> {
+    type Item = Result<Country, CountryParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+
+        let mut field = match self.rest.iter().position(|&b| b == self.delimiter) {
+            Some(end) => {
+                let field = &self.rest[..end];
+                self.rest = &self.rest[end + 1..];
+                field
+            }
+            None => {
+                let field = self.rest;
+                self.rest = &[];
+                field
+            }
+        };
+
+        // Lines of a CRLF file end in `\r` when split on `\n`.
+        if self.delimiter == b'\n' {
+            if let [rest @ .., b'\r'] = field {
+                field = rest;
+            }
+        }
+
+        Some(Country::from_bytes(field))
+    }
+}
+
+impl<'a> iter::FusedIterator for ParseColumn<'a> {}
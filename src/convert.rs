@@ -0,0 +1,66 @@
+use core::convert::TryFrom;
+
+use error::CountryParseError;
+use Country;
+
+impl<'a> TryFrom<&'a str> for Country {
+    type Error = CountryParseError;
+
+    fn try_from(s: &'a str) -> Result<Country, CountryParseError> {
+        s.parse()
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for Country {
+    type Error = CountryParseError;
+
+    fn try_from(b: &'a [u8]) -> Result<Country, CountryParseError> {
+        Country::from_bytes(b)
+    }
+}
+
+impl TryFrom<[u8; 2]> for Country {
+    type Error = CountryParseError;
+
+    fn try_from(b: [u8; 2]) -> Result<Country, CountryParseError> {
+        Country::try_from(&b[..])
+    }
+}
+
+#[cfg(feature = "numeric")]
+impl TryFrom<u16> for Country {
+    type Error = CountryParseError;
+
+    fn try_from(n: u16) -> Result<Country, CountryParseError> {
+        Country::from_numeric(n).ok_or_else(|| CountryParseError::from_numeric(n))
+    }
+}
+
+impl From<Country> for u16 {
+    fn from(country: Country) -> u16 {
+        country.numeric()
+    }
+}
+
+impl AsRef<str> for Country {
+    /// The alpha-2 code, or `""` for `Country::Unspecified`.
+    fn as_ref(&self) -> &str {
+        self.alpha2()
+    }
+}
+
+// Strings compare with a country only on the left, `"PL" == country`.
+// `Country == &str` is not provided: a second `PartialEq` impl on `Country`
+// would leave the right-hand side of `assert_eq!(country, s.parse().unwrap())`
+// ambiguous. `&str` has several impls already, so nothing is lost there.
+impl PartialEq<Country> for str {
+    fn eq(&self, other: &Country) -> bool {
+        self == other.alpha2()
+    }
+}
+
+impl PartialEq<Country> for &str {
+    fn eq(&self, other: &Country) -> bool {
+        *self == other.alpha2()
+    }
+}
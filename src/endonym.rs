@@ -0,0 +1,9 @@
+/// A country's name in one of its own languages, see `Country::endonyms`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Endonym {
+    pub name: &'static str,
+    /// ISO 639-1 code, or ISO 639-3 for languages without one, e.g. `"de"` or `"rar"`.
+    pub language: &'static str,
+    /// ISO 15924 code, e.g. `"Latn"` or `"Cyrl"`.
+    pub script: &'static str,
+}
@@ -0,0 +1,243 @@
+use core::{ fmt, str };
+
+use status::{ self, CodeStatus };
+use Country;
+
+/// The reason a string could not be parsed as a `Country`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
+pub enum ParseErrorKind {
+    /// The input was empty.
+    Empty,
+    /// The input was not 2 or 3 characters long.
+    InvalidLength,
+    /// The input was longer than a code but is not a known country name.
+    UnknownName,
+    /// The input contained non-ASCII characters.
+    NonAscii,
+    /// The input was neither upper-case letters nor digits, e.g. `"pl"` or `"P1"`.
+    InvalidCharacter,
+    /// The input is a well-formed code that is not assigned to any country.
+    Unassigned,
+    /// The input is a code reserved by ISO 3166-1, e.g. `"EU"`.
+    Reserved,
+    /// The input is a code that was once assigned but has since been deleted, e.g. `"YU"`.
+    Retired,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct CountryParseError {
+    kind: ParseErrorKind,
+    len: usize,
+    input: [u8; 3],
+}
+
+impl CountryParseError {
+    pub(crate) fn new(s: &str) -> CountryParseError {
+        let mut input = [0u8; 3];
+        if s.len() <= input.len() {
+            input[..s.len()].copy_from_slice(s.as_bytes());
+        }
+
+        CountryParseError { kind: kind_of(s), len: s.len(), input }
+    }
+
+    /// The error for raw bytes, which need not be UTF-8.
+    pub(crate) fn from_bytes(b: &[u8]) -> CountryParseError {
+        match str::from_utf8(b) {
+            Ok(s) => CountryParseError::new(s),
+            Err(_) => CountryParseError { kind: ParseErrorKind::NonAscii, len: b.len(), input: [0; 3] },
+        }
+    }
+
+    pub(crate) fn with_kind(kind: ParseErrorKind, s: &str) -> CountryParseError {
+        CountryParseError { kind, ..CountryParseError::new(s) }
+    }
+
+    /// The error for a numeric code that is not assigned.
+    #[cfg(feature = "numeric")]
+    pub(crate) fn from_numeric(n: u16) -> CountryParseError {
+        if n >= 1000 {
+            return CountryParseError { kind: ParseErrorKind::InvalidLength, len: 4 + (n >= 10000) as usize, input: [0; 3] };
+        }
+
+        let input = [b'0' + (n / 100) as u8, b'0' + (n / 10 % 10) as u8, b'0' + (n % 10) as u8];
+        CountryParseError { kind: ParseErrorKind::Unassigned, len: input.len(), input }
+    }
+
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+
+    /// The rejected input, if it was short enough to be kept.
+    pub fn input(&self) -> Option<&str> {
+        if self.len <= self.input.len() {
+            str::from_utf8(&self.input[..self.len]).ok()
+        } else {
+            None
+        }
+    }
+
+    /// Countries the input may have been meant as, e.g. `PL` and `PT` for `"PO"`.
+    pub fn suggestions(&self) -> Suggestions {
+        let mut code = [0u8; 3];
+        let len = match self.input() {
+            Some(s) if (s.len() == 2 || s.len() == 3) && s.bytes().all(|b| b.is_ascii_alphabetic()) => s.len(),
+            _ => 0,
+        };
+
+        code[..len].copy_from_slice(&self.input[..len]);
+        code.make_ascii_uppercase();
+
+        let replacement = str::from_utf8(&code[..len]).ok().and_then(status::replacement);
+
+        Suggestions { code, len, replacement, next: 0 }
+    }
+}
+
+fn kind_of(s: &str) -> ParseErrorKind {
+    if s.is_empty() {
+        return ParseErrorKind::Empty;
+    }
+    if !s.is_ascii() {
+        return ParseErrorKind::NonAscii;
+    }
+    if s.len() != 2 && s.len() != 3 {
+        return ParseErrorKind::InvalidLength;
+    }
+    if s.bytes().all(|b| b.is_ascii_digit()) {
+        return ParseErrorKind::Unassigned;
+    }
+    if !s.bytes().all(|b| b.is_ascii_uppercase()) {
+        return ParseErrorKind::InvalidCharacter;
+    }
+
+    match status::code_status(s) {
+        CodeStatus::ExceptionallyReserved | CodeStatus::IndeterminatelyReserved => ParseErrorKind::Reserved,
+        CodeStatus::TransitionallyReserved | CodeStatus::FormerlyUsed => ParseErrorKind::Retired,
+        CodeStatus::Assigned(_) | CodeStatus::UserAssigned | CodeStatus::Unassigned => ParseErrorKind::Unassigned,
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CountryParseError {}
+
+impl fmt::Debug for CountryParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("CountryParseError")
+            .field("kind", &self.kind)
+            .field("input", &self.input())
+            .finish()
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for CountryParseError {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "CountryParseError {{ kind: {}, input: {} }}", self.kind, self.input())
+    }
+}
+
+impl fmt::Display for CountryParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let input = self.input().unwrap_or("");
+
+        match self.kind {
+            ParseErrorKind::Empty => f.write_str("empty country code")?,
+            ParseErrorKind::InvalidLength =>
+                write!(f, "country code must be 2 or 3 characters long, got {}", self.len)?,
+            ParseErrorKind::UnknownName => f.write_str("unknown country name")?,
+            ParseErrorKind::NonAscii => f.write_str("country code contains non-ASCII characters")?,
+            ParseErrorKind::InvalidCharacter => write!(f, "invalid character in country code {:?}", input)?,
+            ParseErrorKind::Unassigned => write!(f, "{:?} is not an assigned ISO 3166-1 code", input)?,
+            ParseErrorKind::Reserved => write!(f, "{:?} is a reserved ISO 3166-1 code", input)?,
+            ParseErrorKind::Retired => write!(f, "{:?} is a retired ISO 3166-1 code", input)?,
+        }
+
+        self.fmt_suggestions(f)
+    }
+}
+
+impl CountryParseError {
+    /// Writes `"; did you mean PL or PT?"`, or nothing without suggestions.
+    pub(crate) fn fmt_suggestions(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut suggestions = self.suggestions().take(3).peekable();
+        if let Some(first) = suggestions.next() {
+            write!(f, "; did you mean {}", first)?;
+            while let Some(country) = suggestions.next() {
+                let sep = if suggestions.peek().is_some() { ", " } else { " or " };
+                write!(f, "{}{}", sep, country)?;
+            }
+            f.write_str("?")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Iterator over the countries suggested for a rejected code, in alpha-2 order.
+#[derive(Clone, Debug)]
+pub struct Suggestions {
+    code: [u8; 3],
+    len: usize,
+    replacement: Option<Country>,
+    next: usize,
+}
+
+impl Suggestions {
+    fn matches(&self, country: Country) -> bool {
+        let code = &self.code[..self.len];
+        if self.replacement == Some(country) {
+            return false;
+        }
+
+        let alpha2 = country.alpha2().as_bytes();
+        if alpha2 == code || (code.len() == 2 && alpha2 == [code[1], code[0]]) {
+            return true;
+        }
+
+        #[cfg(feature = "alpha3")]
+        {
+            let alpha3 = country.alpha3().as_bytes();
+            if alpha3 == code || (code.len() == 2 && alpha3.starts_with(code)) {
+                return true;
+            }
+        }
+
+        #[cfg(feature = "names")]
+        {
+            let name = country.name().as_bytes();
+            if name.len() >= code.len() && name[..code.len()].eq_ignore_ascii_case(code) {
+                return true;
+            }
+        }
+
+        false
+    }
+}
+
+impl Iterator for Suggestions {
+    type Item = Country;
+
+    fn next(&mut self) -> Option<Country> {
+        if self.len == 0 {
+            return None;
+        }
+
+        if self.next == 0 {
+            if let Some(country) = self.replacement.take() {
+                return Some(country);
+            }
+        }
+
+        while let Some(&country) = Country::ALL.get(self.next) {
+            self.next += 1;
+            if self.matches(country) {
+                return Some(country);
+            }
+        }
+
+        None
+    }
+}
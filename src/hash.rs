@@ -0,0 +1,50 @@
+// The hash behind the perfect-hash index in `lookup`. build.rs includes this
+// file to lay out the index, so it must not depend on the rest of the crate.
+
+/// Reads up to eight bytes of `key` from `at` as a little-endian word.
+pub const fn word(key: &[u8], at: usize) -> u64 {
+    if at + 8 <= key.len() {
+        let b = [key[at], key[at + 1], key[at + 2], key[at + 3], key[at + 4], key[at + 5], key[at + 6], key[at + 7]];
+        return u64::from_le_bytes(b);
+    }
+
+    let mut w = 0;
+    let mut i = at;
+    while i < key.len() {
+        w |= (key[i] as u64) << (8 * (i - at));
+        i += 1;
+    }
+    w
+}
+
+/// Mixes in the length and the first 16 and last 8 bytes of the key, which
+/// tell every code and name apart, then applies a 64-bit finalizer so that
+/// short keys spread over all bits.
+pub const fn hash(key: &[u8]) -> u64 {
+    let tail = if key.len() > 24 { key.len() - 8 } else { 16 };
+
+    let mut h = 0xcbf2_9ce4_8422_2325 ^ key.len() as u64;
+    h = (h ^ word(key, 0)).wrapping_mul(0x9e37_79b9_7f4a_7c15).rotate_left(29);
+    if key.len() > 8 {
+        h = (h ^ word(key, 8)).wrapping_mul(0x9e37_79b9_7f4a_7c15).rotate_left(29);
+        h = (h ^ word(key, tail)).wrapping_mul(0x9e37_79b9_7f4a_7c15).rotate_left(29);
+    }
+
+    h ^= h >> 33;
+    h = h.wrapping_mul(0xff51_afd7_ed55_8ccd);
+    h ^= h >> 33;
+    h
+}
+
+/// The bucket holding the key with hash `h`.
+pub const fn bucket(h: u64, buckets: usize) -> usize {
+    (h % buckets as u64) as usize
+}
+
+/// The slot for the key with hash `h`, once its bucket is displaced by `d`.
+/// `slots` must be a power of two.
+pub const fn slot(h: u64, d: u16, slots: usize) -> usize {
+    let f = (h >> 16) as u32;
+    let g = (h >> 32) as u32 | 1;
+    (f.wrapping_add((d as u32).wrapping_mul(g)) as usize) & (slots - 1)
+}
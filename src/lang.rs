@@ -0,0 +1,54 @@
+/// A language that country names are available in, see `Country::name_in`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum Language {
+    English,
+    /// Needs the `names-fr` feature.
+    French,
+    /// Needs the `names-de` feature.
+    German,
+    /// Needs the `names-es` feature.
+    Spanish,
+}
+
+impl Language {
+    /// Every language, whether or not its names are compiled in.
+    pub const ALL: &'static [Language] = &[Language::English, Language::French, Language::German, Language::Spanish];
+
+    /// The ISO 639-1 code, e.g. `"fr"`.
+    pub const fn code(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::French => "fr",
+            Language::German => "de",
+            Language::Spanish => "es",
+        }
+    }
+
+    /// Looks up an ISO 639-1 code in either case. Region and script subtags
+    /// are ignored, so `"fr-CA"` is French.
+    pub fn from_code(s: &str) -> Option<Language> {
+        let primary = s.split(['-', '_']).next().unwrap_or("");
+        Language::ALL.iter()
+            .find(|lang| lang.code().eq_ignore_ascii_case(primary))
+            .cloned()
+    }
+
+    /// Whether the names in this language are compiled in. Without them,
+    /// `Country::name_in` returns the English name.
+    pub fn is_available(self) -> bool {
+        self == Language::English || self.names().is_some()
+    }
+
+    pub(crate) fn names(self) -> Option<&'static [&'static str]> {
+        match self {
+            #[cfg(feature = "names-fr")]
+            Language::French => Some(&::FRENCH_NAMES),
+            #[cfg(feature = "names-de")]
+            Language::German => Some(&::GERMAN_NAMES),
+            #[cfg(feature = "names-es")]
+            Language::Spanish => Some(&::SPANISH_NAMES),
+            _ => None,
+        }
+    }
+}
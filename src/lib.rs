@@ -7,40 +7,107 @@
 // |
 // | - [Wikipedia](http://en.wikipedia.org/wiki/ISO_3166-1)
 
+#![no_std]
+
+#[cfg(any(feature = "std", test))]
+#[macro_use]
+extern crate std;
 #[cfg(feature = "serde")]
 extern crate serde;
-#[macro_use]
-extern crate lazy_static;
+#[cfg(feature = "defmt")]
+extern crate defmt;
 
-use std::{ fmt, str };
-use std::error::Error;
-use std::collections::HashMap;
+use core::{ fmt, iter, slice, str };
 
+mod column;
+mod convert;
+#[cfg(feature = "endonyms")]
+mod endonym;
+#[cfg(feature = "data-table")]
 pub mod data;
+mod error;
+mod hash;
+#[cfg(feature = "names")]
+mod lang;
+mod lookup;
+pub mod map;
+#[cfg(all(feature = "std", feature = "names"))]
+mod normalize;
+#[cfg(all(feature = "std", feature = "names"))]
+mod search;
+pub mod set;
+mod status;
+#[cfg(feature = "serde")]
+pub mod with;
 
-#[derive(Debug)]
-pub enum CountryParseError {
-    InvalidCountryCode(String)
+pub use column::ParseColumn;
+#[cfg(feature = "endonyms")]
+pub use endonym::Endonym;
+pub use error::{ CountryParseError, ParseErrorKind, Suggestions };
+#[cfg(feature = "names")]
+pub use lang::Language;
+pub use map::CountryMap;
+pub use set::CountrySet;
+pub use status::{ Alpha2Code, CodeStatus };
+
+include!(concat!(env!("OUT_DIR"), "/countries.rs"));
+
+/// Looks up a country by its alpha-2, alpha-3 or numeric code at compile
+/// time, failing the build if the code is not assigned.
+///
+/// ```
+/// # #[macro_use] extern crate iso_country;
+/// # use iso_country::Country;
+/// # fn main() {
+/// const HOME: Country = country!("PL");
+/// # #[cfg(feature = "alpha3")]
+/// assert_eq!(Country::DE, country!("DEU"));
+/// # #[cfg(feature = "numeric")]
+/// assert_eq!(Country::AF, country!("004"));
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate iso_country;
+/// # fn main() {
+/// let _ = country!("XX");
+/// # }
+/// ```
+#[macro_export]
+macro_rules! country {
+    ($code:literal) => {{
+        const COUNTRY: $crate::Country = match $crate::Country::from_code($code) {
+            Some(country) => country,
+            None => panic!(concat!("unknown ISO 3166-1 country code: ", $code)),
+        };
+        COUNTRY
+    }};
 }
 
-impl Error for CountryParseError {
-    fn description(&self) -> &str { "error parsing country code" }
+/// The representation of a country matched by `Country::parse_any`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CountryFormat {
+    Alpha2,
+    Alpha3,
+    Numeric,
+    Name,
 }
 
-impl fmt::Display for CountryParseError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        fmt::Debug::fmt(self, f)
-    }
+/// How `Country::from_name_or_alias` matched a name.
+#[cfg(feature = "names")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum NameMatch {
+    /// A name that `Country::from_name` accepts as well.
+    Canonical,
+    /// A common name such as `"UK"` or `"Holland"`, from `data/aliases.tsv`.
+    Alias,
 }
 
 impl str::FromStr for Country {
     type Err = CountryParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        match COUNTRY_CODE_SEARCH_TABLE.binary_search_by(|&(o, _)| o.cmp(s)) {
-            Ok(pos) => Ok(COUNTRY_CODE_SEARCH_TABLE[pos].1),
-            Err(_)  => Err(CountryParseError::InvalidCountryCode(s.to_string()))
-        }
+        Country::from_bytes(s.as_bytes())
     }
 }
 
@@ -53,800 +120,252 @@ impl fmt::Display for Country  {
     }
 }
 
+#[cfg(feature = "defmt")]
+impl defmt::Format for Country {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "{=str}", self.alpha2())
+    }
+}
+
 impl Country {
+    /// The number of countries in `Country::ALL`.
+    pub const COUNT: usize = Country::ALL.len();
+
+    pub fn iter() -> iter::Copied<slice::Iter<'static, Country>> {
+        Country::ALL.iter().copied()
+    }
+
+    pub fn from_index(index: usize) -> Option<Country> {
+        Country::ALL.get(index).copied()
+    }
+
+    /// Parses a code like `FromStr`, but rejects the empty string instead of
+    /// returning `Country::Unspecified`.
+    pub fn parse_strict(s: &str) -> Result<Country, CountryParseError> {
+        match s {
+            "" => Err(CountryParseError::new(s)),
+            _ => s.parse(),
+        }
+    }
+
+    /// Parses an alpha-2, alpha-3 or numeric code from raw bytes, e.g. a field
+    /// of a CSV file, without UTF-8 validation. Like `FromStr`, empty input
+    /// is `Country::Unspecified`.
+    pub fn from_bytes(b: &[u8]) -> Result<Country, CountryParseError> {
+        match b.len() {
+            0 => return Ok(Country::Unspecified),
+            2 | 3 => if let Some((country, _)) = lookup::search_bytes(b) {
+                return Ok(country);
+            },
+            _ => {}
+        }
+
+        Err(CountryParseError::from_bytes(b))
+    }
+
+    /// Parses every field with `Country::from_bytes`.
+    #[cfg(feature = "std")]
+    pub fn parse_many(fields: &[&[u8]]) -> std::vec::Vec<Result<Country, CountryParseError>> {
+        fields.iter().map(|b| Country::from_bytes(b)).collect()
+    }
+
+    /// Parses a column of codes separated by `delimiter`, e.g. `b'\n'`,
+    /// without allocating. A trailing delimiter does not start another field.
+    /// With `b'\n'`, a `b'\r'` before each delimiter is dropped, so CRLF line
+    /// endings work too.
+    ///
+    /// ```
+    /// # use iso_country::Country;
+    /// let countries: Vec<_> = Country::parse_column(b"PL\nDE\n\nFR\n", b'\n').collect();
+    /// assert_eq!(vec![Ok(Country::PL), Ok(Country::DE), Ok(Country::Unspecified), Ok(Country::FR)], countries);
+    /// # #[cfg(all(feature = "alpha3", feature = "numeric"))]
+    /// assert_eq!(2, Country::parse_column(b"DEU,276", b',').filter(|c| *c == Ok(Country::DE)).count());
+    /// ```
+    pub fn parse_column(data: &[u8], delimiter: u8) -> ParseColumn<'_> {
+        ParseColumn::new(data, delimiter)
+    }
+
+    /// Parses a code like `FromStr`, but ignores surrounding whitespace and
+    /// punctuation and is case-insensitive, so `" pl\n"` and `"(Deu)"` parse.
+    pub fn parse_lenient(s: &str) -> Result<Country, CountryParseError> {
+        let code = s.trim_matches(|c: char| c.is_whitespace() || c.is_ascii_punctuation());
+
+        let mut buf = [0u8; 3];
+        if code.len() > buf.len() || !code.is_ascii() {
+            return Err(CountryParseError::new(code));
+        }
+
+        let buf = &mut buf[..code.len()];
+        buf.copy_from_slice(code.as_bytes());
+        buf.make_ascii_uppercase();
+
+        str::from_utf8(buf)
+            .expect("upper-casing ASCII keeps it valid UTF-8")
+            .parse()
+    }
+
+    /// Parses an alpha-2, alpha-3 or numeric code or a country name, and
+    /// reports which of them matched.
+    pub fn parse_any(s: &str) -> Result<(Country, CountryFormat), CountryParseError> {
+        lookup::search(s)
+            .ok_or_else(|| match s.len() {
+                0..=3 => CountryParseError::new(s),
+                _ => CountryParseError::with_kind(ParseErrorKind::UnknownName, s),
+            })
+    }
+
+    /// Parses an alpha-2 code in either case, mapping codes such as `UK` and
+    /// `EL` to the country they are commonly used for.
+    pub fn from_alpha2_normalized(s: &str) -> Result<Country, CountryParseError> {
+        let code = s.parse::<Alpha2Code>()?.normalize();
+        code.country().ok_or_else(|| CountryParseError::new(code.as_str()))
+    }
+
+    #[cfg(feature = "data-table")]
+    pub fn info(self) -> Option<&'static data::CountryInfo> {
+        self.index().map(|i| &data::COUNTRIES[i])
+    }
+
+    /// The alpha-2 code, or `""` for `Country::Unspecified`.
+    pub fn alpha2(self) -> &'static str {
+        self.index().map_or("", |i| ALPHA2_STRS[i])
+    }
+
+    /// The ISO 3166-1 English short name, in the list's "short name lower case"
+    /// form, e.g. `"Bolivia (Plurinational State of)"`.
+    #[cfg(feature = "names")]
     pub fn name(self) -> &'static str {
-        use Country::*;
-        match self {
-            Unspecified => "",
-            AD => "Andorra",
-            AE => "United Arab Emirates",
-            AF => "Afghanistan",
-            AG => "Antigua and Barbuda",
-            AI => "Anguilla",
-            AL => "Albania",
-            AM => "Armenia",
-            AO => "Angola",
-            AQ => "Antarctica",
-            AR => "Argentina",
-            AS => "American Samoa",
-            AT => "Austria",
-            AU => "Australia",
-            AW => "Aruba",
-            AX => "Åland Islands",
-            AZ => "Azerbaijan",
-            BA => "Bosnia and Herzegovina",
-            BB => "Barbados",
-            BD => "Bangladesh",
-            BE => "Belgium",
-            BF => "Burkina Faso",
-            BG => "Bulgaria",
-            BH => "Bahrain",
-            BI => "Burundi",
-            BJ => "Benin",
-            BL => "Saint Barthélemy",
-            BM => "Bermuda",
-            BN => "Brunei Darussalam",
-            BO => "Bolivia (Plurinational State of)",
-            BQ => "Bonaire, Sint Eustatius and Saba",
-            BR => "Brazil",
-            BS => "Bahamas",
-            BT => "Bhutan",
-            BV => "Bouvet Island",
-            BW => "Botswana",
-            BY => "Belarus",
-            BZ => "Belize",
-            CA => "Canada",
-            CC => "Cocos (Keeling) Islands",
-            CD => "Congo (Democratic Republic of the)",
-            CF => "Central African Republic",
-            CG => "Congo",
-            CH => "Switzerland",
-            CI => "Côte d'Ivoire",
-            CK => "Cook Islands",
-            CL => "Chile",
-            CM => "Cameroon",
-            CN => "China",
-            CO => "Colombia",
-            CR => "Costa Rica",
-            CU => "Cuba",
-            CV => "Cabo Verde",
-            CW => "Curaçao",
-            CX => "Christmas Island",
-            CY => "Cyprus",
-            CZ => "Czech Republic",
-            DE => "Germany",
-            DJ => "Djibouti",
-            DK => "Denmark",
-            DM => "Dominica",
-            DO => "Dominican Republic",
-            DZ => "Algeria",
-            EC => "Ecuador",
-            EE => "Estonia",
-            EG => "Egypt",
-            EH => "Western Sahara",
-            ER => "Eritrea",
-            ES => "Spain",
-            ET => "Ethiopia",
-            FI => "Finland",
-            FJ => "Fiji",
-            FK => "Falkland Islands",
-            FM => "Micronesia (Federated States of)",
-            FO => "Faroe Islands",
-            FR => "France",
-            GA => "Gabon",
-            GB => "United Kingdom of Great Britain and Northern Ireland",
-            GD => "Grenada",
-            GE => "Georgia",
-            GF => "French Guiana",
-            GG => "Guernsey",
-            GH => "Ghana",
-            GI => "Gibraltar",
-            GL => "Greenland",
-            GM => "Gambia",
-            GN => "Guinea",
-            GP => "Guadeloupe",
-            GQ => "Equatorial Guinea",
-            GR => "Greece",
-            GS => "South Georgia and the South Sandwich Islands",
-            GT => "Guatemala",
-            GU => "Guam",
-            GW => "Guinea-Bissau",
-            GY => "Guyana",
-            HK => "Hong Kong",
-            HM => "Heard Island and McDonald Islands",
-            HN => "Honduras",
-            HR => "Croatia",
-            HT => "Haiti",
-            HU => "Hungary",
-            ID => "Indonesia",
-            IE => "Ireland",
-            IL => "Israel",
-            IM => "Isle of Man",
-            IN => "India",
-            IO => "British Indian Ocean Territory",
-            IQ => "Iraq",
-            IR => "Iran (Islamic Republic of)",
-            IS => "Iceland",
-            IT => "Italy",
-            JE => "Jersey",
-            JM => "Jamaica",
-            JO => "Jordan",
-            JP => "Japan",
-            KE => "Kenya",
-            KG => "Kyrgyzstan",
-            KH => "Cambodia",
-            KI => "Kiribati",
-            KM => "Comoros",
-            KN => "Saint Kitts and Nevis",
-            KP => "Korea (Democratic People's Republic of)",
-            KR => "Korea (Republic of)",
-            KW => "Kuwait",
-            KY => "Cayman Islands",
-            KZ => "Kazakhstan",
-            LA => "Lao People's Democratic Republic",
-            LB => "Lebanon",
-            LC => "Saint Lucia",
-            LI => "Liechtenstein",
-            LK => "Sri Lanka",
-            LR => "Liberia",
-            LS => "Lesotho",
-            LT => "Lithuania",
-            LU => "Luxembourg",
-            LV => "Latvia",
-            LY => "Libya",
-            MA => "Morocco",
-            MC => "Monaco",
-            MD => "Moldova (Republic of)",
-            ME => "Montenegro",
-            MF => "Saint Martin (French part)",
-            MG => "Madagascar",
-            MH => "Marshall Islands",
-            MK => "Macedonia (the former Yugoslav Republic of)",
-            ML => "Mali",
-            MM => "Myanmar",
-            MN => "Mongolia",
-            MO => "Macao",
-            MP => "Northern Mariana Islands",
-            MQ => "Martinique",
-            MR => "Mauritania",
-            MS => "Montserrat",
-            MT => "Malta",
-            MU => "Mauritius",
-            MV => "Maldives",
-            MW => "Malawi",
-            MX => "Mexico",
-            MY => "Malaysia",
-            MZ => "Mozambique",
-            NA => "Namibia",
-            NC => "New Caledonia",
-            NE => "Niger",
-            NF => "Norfolk Island",
-            NG => "Nigeria",
-            NI => "Nicaragua",
-            NL => "Netherlands",
-            NO => "Norway",
-            NP => "Nepal",
-            NR => "Nauru",
-            NU => "Niue",
-            NZ => "New Zealand",
-            OM => "Oman",
-            PA => "Panama",
-            PE => "Peru",
-            PF => "French Polynesia",
-            PG => "Papua New Guinea",
-            PH => "Philippines",
-            PK => "Pakistan",
-            PL => "Poland",
-            PM => "Saint Pierre and Miquelon",
-            PN => "Pitcairn",
-            PR => "Puerto Rico",
-            PS => "Palestine, State of",
-            PT => "Portugal",
-            PW => "Palau",
-            PY => "Paraguay",
-            QA => "Qatar",
-            RE => "Réunion",
-            RO => "Romania",
-            RS => "Serbia",
-            RU => "Russian Federation",
-            RW => "Rwanda",
-            SA => "Saudi Arabia",
-            SB => "Solomon Islands",
-            SC => "Seychelles",
-            SD => "Sudan",
-            SE => "Sweden",
-            SG => "Singapore",
-            SH => "Saint Helena, Ascension and Tristan da Cunha",
-            SI => "Slovenia",
-            SJ => "Svalbard and Jan Mayen",
-            SK => "Slovakia",
-            SL => "Sierra Leone",
-            SM => "San Marino",
-            SN => "Senegal",
-            SO => "Somalia",
-            SR => "Suriname",
-            SS => "South Sudan",
-            ST => "Sao Tome and Principe",
-            SV => "El Salvador",
-            SX => "Sint Maarten (Dutch part)",
-            SY => "Syrian Arab Republic",
-            SZ => "Swaziland",
-            TC => "Turks and Caicos Islands",
-            TD => "Chad",
-            TF => "French Southern Territories",
-            TG => "Togo",
-            TH => "Thailand",
-            TJ => "Tajikistan",
-            TK => "Tokelau",
-            TL => "Timor-Leste",
-            TM => "Turkmenistan",
-            TN => "Tunisia",
-            TO => "Tonga",
-            TR => "Turkey",
-            TT => "Trinidad and Tobago",
-            TV => "Tuvalu",
-            TW => "Taiwan, Province of China[a]",
-            TZ => "Tanzania, United Republic of",
-            UA => "Ukraine",
-            UG => "Uganda",
-            UM => "United States Minor Outlying Islands",
-            US => "United States of America",
-            UY => "Uruguay",
-            UZ => "Uzbekistan",
-            VA => "Holy See",
-            VC => "Saint Vincent and the Grenadines",
-            VE => "Venezuela (Bolivarian Republic of)",
-            VG => "Virgin Islands (British)",
-            VI => "Virgin Islands (U.S.)",
-            VN => "Viet Nam",
-            VU => "Vanuatu",
-            WF => "Wallis and Futuna",
-            WS => "Samoa",
-            YE => "Yemen",
-            YT => "Mayotte",
-            ZA => "South Africa",
-            ZM => "Zambia",
-            ZW => "Zimbabwe",
+        self.index().map_or("", |i| NAMES[i])
+    }
+
+    /// The short name in `lang`, falling back to the English name when the
+    /// language's feature is disabled or it has no entry for this country.
+    ///
+    /// ```
+    /// # use iso_country::{ Country, Language };
+    /// # #[cfg(feature = "names-fr")]
+    /// assert_eq!("Allemagne", Country::DE.name_in(Language::French));
+    /// assert_eq!("Germany", Country::DE.name_in(Language::English));
+    /// ```
+    #[cfg(feature = "names")]
+    pub fn name_in(self, lang: Language) -> &'static str {
+        match (self.index(), lang.names()) {
+            (Some(i), Some(names)) if !names[i].is_empty() => names[i],
+            _ => self.name(),
         }
     }
 
-    pub fn from_name(s: &str) -> Option<Country> {
-        use Country::*;
-        Some(match s {
-            "Andorra" => AD,
-            "United Arab Emirates" => AE,
-            "Afghanistan" => AF,
-            "Antigua and Barbuda" => AG,
-            "Anguilla" => AI,
-            "Albania" => AL,
-            "Armenia" => AM,
-            "Angola" => AO,
-            "Antarctica" => AQ,
-            "Argentina" => AR,
-            "American Samoa" => AS,
-            "Austria" => AT,
-            "Australia" => AU,
-            "Aruba" => AW,
-            "Åland Islands" => AX,
-            "Azerbaijan" => AZ,
-            "Bosnia and Herzegovina" => BA,
-            "Barbados" => BB,
-            "Bangladesh" => BD,
-            "Belgium" => BE,
-            "Burkina Faso" => BF,
-            "Bulgaria" => BG,
-            "Bahrain" => BH,
-            "Burundi" => BI,
-            "Benin" => BJ,
-            "Saint Barthélemy" => BL,
-            "Bermuda" => BM,
-            "Brunei Darussalam" => BN,
-            "Bolivia (Plurinational State of)" => BO,
-            "Bonaire, Sint Eustatius and Saba" => BQ,
-            "Brazil" => BR,
-            "Bahamas" => BS,
-            "Bhutan" => BT,
-            "Bouvet Island" => BV,
-            "Botswana" => BW,
-            "Belarus" => BY,
-            "Belize" => BZ,
-            "Canada" => CA,
-            "Cocos (Keeling) Islands" => CC,
-            "Congo (Democratic Republic of the)" => CD,
-            "Central African Republic" => CF,
-            "Congo" => CG,
-            "Switzerland" => CH,
-            "Côte d'Ivoire" => CI,
-            "Cook Islands" => CK,
-            "Chile" => CL,
-            "Cameroon" => CM,
-            "China" => CN,
-            "Colombia" => CO,
-            "Costa Rica" => CR,
-            "Cuba" => CU,
-            "Cabo Verde" => CV,
-            "Curaçao" => CW,
-            "Christmas Island" => CX,
-            "Cyprus" => CY,
-            "Czech Republic" => CZ,
-            "Germany" => DE,
-            "Djibouti" => DJ,
-            "Denmark" => DK,
-            "Dominica" => DM,
-            "Dominican Republic" => DO,
-            "Algeria" => DZ,
-            "Ecuador" => EC,
-            "Estonia" => EE,
-            "Egypt" => EG,
-            "Western Sahara" => EH,
-            "Eritrea" => ER,
-            "Spain" => ES,
-            "Ethiopia" => ET,
-            "Finland" => FI,
-            "Fiji" => FJ,
-            "Falkland Islands" => FK,
-            "Micronesia (Federated States of)" => FM,
-            "Micronesia" => FM,
-            "Faroe Islands" => FO,
-            "France" => FR,
-            "Gabon" => GA,
-            "United Kingdom of Great Britain and Northern Ireland" => GB,
-            "United Kingdom of Great Britain" => GB,
-            "Grenada" => GD,
-            "Georgia" => GE,
-            "French Guiana" => GF,
-            "Guernsey" => GG,
-            "Ghana" => GH,
-            "Gibraltar" => GI,
-            "Greenland" => GL,
-            "Gambia" => GM,
-            "Guinea" => GN,
-            "Guadeloupe" => GP,
-            "Equatorial Guinea" => GQ,
-            "Greece" => GR,
-            "South Georgia and the South Sandwich Islands" => GS,
-            "Guatemala" => GT,
-            "Guam" => GU,
-            "Guinea-Bissau" => GW,
-            "Guyana" => GY,
-            "Hong Kong" => HK,
-            "Heard Island and McDonald Islands" => HM,
-            "Honduras" => HN,
-            "Croatia" => HR,
-            "Haiti" => HT,
-            "Hungary" => HU,
-            "Indonesia" => ID,
-            "Ireland" => IE,
-            "Israel" => IL,
-            "Isle of Man" => IM,
-            "India" => IN,
-            "British Indian Ocean Territory" => IO,
-            "Iraq" => IQ,
-            "Iran (Islamic Republic of)" => IR,
-            "Iran" => IR,
-            "Iceland" => IS,
-            "Italy" => IT,
-            "Jersey" => JE,
-            "Jamaica" => JM,
-            "Jordan" => JO,
-            "Japan" => JP,
-            "Kenya" => KE,
-            "Kyrgyzstan" => KG,
-            "Cambodia" => KH,
-            "Kiribati" => KI,
-            "Comoros" => KM,
-            "Saint Kitts and Nevis" => KN,
-            "Korea (Democratic People's Republic of)" => KP,
-            "Korea (Republic of)" => KR,
-            "Kuwait" => KW,
-            "Cayman Islands" => KY,
-            "Kazakhstan" => KZ,
-            "Lao People's Democratic Republic" => LA,
-            "Lebanon" => LB,
-            "Saint Lucia" => LC,
-            "Liechtenstein" => LI,
-            "Sri Lanka" => LK,
-            "Liberia" => LR,
-            "Lesotho" => LS,
-            "Lithuania" => LT,
-            "Luxembourg" => LU,
-            "Latvia" => LV,
-            "Libya" => LY,
-            "Morocco" => MA,
-            "Monaco" => MC,
-            "Moldova (Republic of)" => MD,
-            "Montenegro" => ME,
-            "Saint Martin (French part)" => MF,
-            "Madagascar" => MG,
-            "Marshall Islands" => MH,
-            "Macedonia (the former Yugoslav Republic of)" => MK,
-            "Macedonia" => MK,
-            "Mali" => ML,
-            "Myanmar" => MM,
-            "Mongolia" => MN,
-            "Macao" => MO,
-            "Northern Mariana Islands" => MP,
-            "Martinique" => MQ,
-            "Mauritania" => MR,
-            "Montserrat" => MS,
-            "Malta" => MT,
-            "Mauritius" => MU,
-            "Maldives" => MV,
-            "Malawi" => MW,
-            "Mexico" => MX,
-            "Malaysia" => MY,
-            "Mozambique" => MZ,
-            "Namibia" => NA,
-            "New Caledonia" => NC,
-            "Niger" => NE,
-            "Norfolk Island" => NF,
-            "Nigeria" => NG,
-            "Nicaragua" => NI,
-            "Netherlands" => NL,
-            "Norway" => NO,
-            "Nepal" => NP,
-            "Nauru" => NR,
-            "Niue" => NU,
-            "New Zealand" => NZ,
-            "Oman" => OM,
-            "Panama" => PA,
-            "Peru" => PE,
-            "French Polynesia" => PF,
-            "Papua New Guinea" => PG,
-            "Philippines" => PH,
-            "Pakistan" => PK,
-            "Poland" => PL,
-            "Saint Pierre and Miquelon" => PM,
-            "Pitcairn" => PN,
-            "Puerto Rico" => PR,
-            "Palestine, State of" => PS,
-            "Portugal" => PT,
-            "Palau" => PW,
-            "Paraguay" => PY,
-            "Qatar" => QA,
-            "Réunion" => RE,
-            "Romania" => RO,
-            "Serbia" => RS,
-            "Russian Federation" => RU,
-            "Rwanda" => RW,
-            "Saudi Arabia" => SA,
-            "Solomon Islands" => SB,
-            "Seychelles" => SC,
-            "Sudan" => SD,
-            "Sweden" => SE,
-            "Singapore" => SG,
-            "Saint Helena, Ascension and Tristan da Cunha" => SH,
-            "Slovenia" => SI,
-            "Svalbard and Jan Mayen" => SJ,
-            "Slovakia" => SK,
-            "Sierra Leone" => SL,
-            "San Marino" => SM,
-            "Senegal" => SN,
-            "Somalia" => SO,
-            "Suriname" => SR,
-            "South Sudan" => SS,
-            "Sao Tome and Principe" => ST,
-            "El Salvador" => SV,
-            "Sint Maarten (Dutch part)" => SX,
-            "Syrian Arab Republic" => SY,
-            "Swaziland" => SZ,
-            "Turks and Caicos Islands" => TC,
-            "Chad" => TD,
-            "French Southern Territories" => TF,
-            "Togo" => TG,
-            "Thailand" => TH,
-            "Tajikistan" => TJ,
-            "Tokelau" => TK,
-            "Timor-Leste" => TL,
-            "Turkmenistan" => TM,
-            "Tunisia" => TN,
-            "Tonga" => TO,
-            "Turkey" => TR,
-            "Trinidad and Tobago" => TT,
-            "Tuvalu" => TV,
-            "Taiwan, Province of China[a]" => TW,
-            "Tanzania, United Republic of" => TZ,
-            "Tanzania" => TZ,
-            "Ukraine" => UA,
-            "Uganda" => UG,
-            "United States Minor Outlying Islands" => UM,
-            "United States of America" => US,
-            "Uruguay" => UY,
-            "Uzbekistan" => UZ,
-            "Holy See" => VA,
-            "Saint Vincent and the Grenadines" => VC,
-            "Venezuela (Bolivarian Republic of)" => VE,
-            "Venezuela" => VE,
-            "Virgin Islands (British)" => VG,
-            "Virgin Islands (U.S.)" => VI,
-            "Viet Nam" => VN,
-            "Vanuatu" => VU,
-            "Wallis and Futuna" => WF,
-            "Samoa" => WS,
-            "Yemen" => YE,
-            "Mayotte" => YT,
-            "South Africa" => ZA,
-            "Zambia" => ZM,
-            "Zimbabwe" => ZW,
-            _ => return None
-        })
+    /// The country's name in its own official or national languages, most
+    /// widely used first, e.g. `"Schweiz"`, `"Suisse"`, `"Svizzera"` and
+    /// `"Svizra"` for `CH`. Empty for uninhabited territories.
+    #[cfg(feature = "endonyms")]
+    pub fn endonyms(self) -> &'static [Endonym] {
+        match self.index() {
+            Some(i) => &ENDONYMS[ENDONYM_RANGES[i].0 as usize..ENDONYM_RANGES[i].1 as usize],
+            None => &[],
+        }
     }
-}
 
-#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub enum Country {
-    Unspecified = 0,
-    AD = 20,
-    AE = 784,
-    AF = 4,
-    AG = 28,
-    AI = 660,
-    AL = 8,
-    AM = 51,
-    AO = 24,
-    AQ = 10,
-    AR = 32,
-    AS = 16,
-    AT = 40,
-    AU = 36,
-    AW = 533,
-    AX = 248,
-    AZ = 31,
-    BA = 70,
-    BB = 52,
-    BD = 50,
-    BE = 56,
-    BF = 854,
-    BG = 100,
-    BH = 48,
-    BI = 108,
-    BJ = 204,
-    BL = 652,
-    BM = 60,
-    BN = 96,
-    BO = 68,
-    BQ = 535,
-    BR = 76,
-    BS = 44,
-    BT = 64,
-    BV = 74,
-    BW = 72,
-    BY = 112,
-    BZ = 84,
-    CA = 124,
-    CC = 166,
-    CD = 180,
-    CF = 140,
-    CG = 178,
-    CH = 756,
-    CI = 384,
-    CK = 184,
-    CL = 152,
-    CM = 120,
-    CN = 156,
-    CO = 170,
-    CR = 188,
-    CU = 192,
-    CV = 132,
-    CW = 531,
-    CX = 162,
-    CY = 196,
-    CZ = 203,
-    DE = 276,
-    DJ = 262,
-    DK = 208,
-    DM = 212,
-    DO = 214,
-    DZ = 12,
-    EC = 218,
-    EE = 233,
-    EG = 818,
-    EH = 732,
-    ER = 232,
-    ES = 724,
-    ET = 231,
-    FI = 246,
-    FJ = 242,
-    FK = 238,
-    FM = 583,
-    FO = 234,
-    FR = 250,
-    GA = 266,
-    GB = 826,
-    GD = 308,
-    GE = 268,
-    GF = 254,
-    GG = 831,
-    GH = 288,
-    GI = 292,
-    GL = 304,
-    GM = 270,
-    GN = 324,
-    GP = 312,
-    GQ = 226,
-    GR = 300,
-    GS = 239,
-    GT = 320,
-    GU = 316,
-    GW = 624,
-    GY = 328,
-    HK = 344,
-    HM = 334,
-    HN = 340,
-    HR = 191,
-    HT = 332,
-    HU = 348,
-    ID = 360,
-    IE = 372,
-    IL = 376,
-    IM = 833,
-    IN = 356,
-    IO = 86,
-    IQ = 368,
-    IR = 364,
-    IS = 352,
-    IT = 380,
-    JE = 832,
-    JM = 388,
-    JO = 400,
-    JP = 392,
-    KE = 404,
-    KG = 417,
-    KH = 116,
-    KI = 296,
-    KM = 174,
-    KN = 659,
-    KP = 408,
-    KR = 410,
-    KW = 414,
-    KY = 136,
-    KZ = 398,
-    LA = 418,
-    LB = 422,
-    LC = 662,
-    LI = 438,
-    LK = 144,
-    LR = 430,
-    LS = 426,
-    LT = 440,
-    LU = 442,
-    LV = 428,
-    LY = 434,
-    MA = 504,
-    MC = 492,
-    MD = 498,
-    ME = 499,
-    MF = 663,
-    MG = 450,
-    MH = 584,
-    MK = 807,
-    ML = 466,
-    MM = 104,
-    MN = 496,
-    MO = 446,
-    MP = 580,
-    MQ = 474,
-    MR = 478,
-    MS = 500,
-    MT = 470,
-    MU = 480,
-    MV = 462,
-    MW = 454,
-    MX = 484,
-    MY = 458,
-    MZ = 508,
-    NA = 516,
-    NC = 540,
-    NE = 562,
-    NF = 574,
-    NG = 566,
-    NI = 558,
-    NL = 528,
-    NO = 578,
-    NP = 524,
-    NR = 520,
-    NU = 570,
-    NZ = 554,
-    OM = 512,
-    PA = 591,
-    PE = 604,
-    PF = 258,
-    PG = 598,
-    PH = 608,
-    PK = 586,
-    PL = 616,
-    PM = 666,
-    PN = 612,
-    PR = 630,
-    PS = 275,
-    PT = 620,
-    PW = 585,
-    PY = 600,
-    QA = 634,
-    RE = 638,
-    RO = 642,
-    RS = 688,
-    RU = 643,
-    RW = 646,
-    SA = 682,
-    SB = 90,
-    SC = 690,
-    SD = 729,
-    SE = 752,
-    SG = 702,
-    SH = 654,
-    SI = 705,
-    SJ = 744,
-    SK = 703,
-    SL = 694,
-    SM = 674,
-    SN = 686,
-    SO = 706,
-    SR = 740,
-    SS = 728,
-    ST = 678,
-    SV = 222,
-    SX = 534,
-    SY = 760,
-    SZ = 748,
-    TC = 796,
-    TD = 148,
-    TF = 260,
-    TG = 768,
-    TH = 764,
-    TJ = 762,
-    TK = 772,
-    TL = 626,
-    TM = 795,
-    TN = 788,
-    TO = 776,
-    TR = 792,
-    TT = 780,
-    TV = 798,
-    TW = 158,
-    TZ = 834,
-    UA = 804,
-    UG = 800,
-    UM = 581,
-    US = 840,
-    UY = 858,
-    UZ = 860,
-    VA = 336,
-    VC = 670,
-    VE = 862,
-    VG = 92,
-    VI = 850,
-    VN = 704,
-    VU = 548,
-    WF = 876,
-    WS = 882,
-    YE = 887,
-    YT = 175,
-    ZA = 710,
-    ZM = 894,
-    ZW = 716,
-}
+    /// The formal name, e.g. `"Plurinational State of Bolivia"` or
+    /// `"Republic of Poland"`. Territories without one use the short name.
+    #[cfg(feature = "names")]
+    pub fn name_official(self) -> &'static str {
+        self.index().map_or("", |i| OFFICIAL_NAMES[i])
+    }
 
-lazy_static! {
-    static ref INVERTED_COUNTRY_CODES: HashMap<Country, &'static str> = {
-        let mut codes = HashMap::new();
+    #[cfg(feature = "names")]
+    pub const fn from_name(s: &str) -> Option<Country> {
+        match lookup::search(s) {
+            Some((country, CountryFormat::Name)) => Some(country),
+            _ => None,
+        }
+    }
 
-        for &(country_name, country_code) in COUNTRY_CODE_SEARCH_TABLE {
-            codes.insert(country_code, country_name);
+    /// Like `from_name`, but also accepts common names such as
+    /// `"United Kingdom"`, `"Russia"`, `"Ivory Coast"` or `"USA"`, and
+    /// reports whether an alias matched. Ambiguous names such as `"Korea"`
+    /// are not aliases; `Country::search` suggests every match for them.
+    #[cfg(feature = "names")]
+    pub const fn from_name_or_alias(s: &str) -> Option<(Country, NameMatch)> {
+        match lookup::search_name(s) {
+            Some((country, false)) => Some((country, NameMatch::Canonical)),
+            Some((country, true)) => Some((country, NameMatch::Alias)),
+            None => None,
         }
+    }
 
-        codes
-    };
+    /// Finds countries whose names or aliases resemble `query`, best first,
+    /// for suggesting corrections to misspelt input. Scores run from 1.0 for
+    /// an exact match (ignoring case and punctuation) down to 0.6.
+    ///
+    /// ```
+    /// # use iso_country::Country;
+    /// assert_eq!(Country::PH, Country::search("Phillipines")[0].0);
+    /// assert_eq!(Country::CO, Country::search("Columbia")[0].0);
+    /// ```
+    #[cfg(all(feature = "std", feature = "names"))]
+    pub fn search(query: &str) -> std::vec::Vec<(Country, f32)> {
+        search::search(query)
+    }
+
+    #[cfg(feature = "alpha3")]
+    pub fn alpha3(self) -> &'static str {
+        self.index().map_or("", |i| ALPHA3_STRS[i])
+    }
+
+    pub const fn from_alpha2(s: &str) -> Option<Country> {
+        match lookup::search(s) {
+            Some((country, CountryFormat::Alpha2)) => Some(country),
+            _ => None,
+        }
+    }
+
+    #[cfg(feature = "alpha3")]
+    pub const fn from_alpha3(s: &str) -> Option<Country> {
+        match lookup::search(s) {
+            Some((country, CountryFormat::Alpha3)) => Some(country),
+            _ => None,
+        }
+    }
+
+    /// Looks up an alpha-2, alpha-3 or numeric code. Unlike `FromStr`, the
+    /// empty string is not accepted. Usable in constant expressions, see `country!`.
+    pub const fn from_code(s: &str) -> Option<Country> {
+        match lookup::search(s) {
+            Some((_, CountryFormat::Name)) | None => None,
+            Some((country, _)) => Some(country),
+        }
+    }
+
+    pub const fn numeric(self) -> u16 {
+        self as u16
+    }
+
+    #[cfg(feature = "numeric")]
+    pub fn numeric_str(self) -> &'static str {
+        self.index().map_or("", |i| NUMERIC_STRS[i])
+    }
+
+    #[cfg(feature = "numeric")]
+    pub const fn from_numeric(n: u16) -> Option<Country> {
+        lookup::search_numeric(COUNTRY_NUMERIC_SEARCH_TABLE, n)
+    }
+
+    /// Looks up a numeric code written with exactly three digits, e.g. `"004"`.
+    #[cfg(feature = "numeric")]
+    pub const fn from_numeric_str(s: &str) -> Option<Country> {
+        match lookup::search(s) {
+            Some((country, CountryFormat::Numeric)) => Some(country),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(feature = "serde")]
 impl serde::Serialize for Country {
    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: serde::Serializer
    {
-       let country_name = INVERTED_COUNTRY_CODES.get(self)
-           .ok_or_else(|| serde::ser::Error::custom("Impossible, since all variants have their country name"))?;
-
-       serializer.serialize_str(country_name)
+       serializer.serialize_str(self.alpha2())
    }
 }
 
@@ -855,21 +374,21 @@ impl<'de> serde::Deserialize<'de> for Country {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: serde::Deserializer<'de> {
       use serde::de::Visitor;
       use serde::de::Unexpected;
-      use std::fmt;
-      use std::str::FromStr;
+      use core::fmt;
+      use core::str::FromStr;
       struct CountryVisitor;
 
       impl <'de> Visitor<'de> for CountryVisitor {
             type Value = Country;
 
             fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-                  formatter.write_str("valid 2 letter country code")
+                  formatter.write_str("an alpha-2, alpha-3 or numeric country code")
             }
 
             fn visit_str<E>(self, value: &str) -> Result<Country, E> where E: serde::de::Error {
                   match Country::from_str(value) {
                         Ok(country) => Ok(country),
-                        Err(_) => Err(E::invalid_value(Unexpected::Str(value), &"2 letter country code")),
+                        Err(_) => Err(E::invalid_value(Unexpected::Str(value), &self)),
                   }
             }
       }
@@ -878,264 +397,14 @@ impl<'de> serde::Deserialize<'de> for Country {
     }
 }
 
-const COUNTRY_CODE_SEARCH_TABLE : &[(&str, Country)] = &[
-    ("",    Country::Unspecified),
-    ("AD",  Country::AD),
-    ("AE",  Country::AE),
-    ("AF",  Country::AF),
-    ("AG",  Country::AG),
-    ("AI",  Country::AI),
-    ("AL",  Country::AL),
-    ("AM",  Country::AM),
-    ("AO",  Country::AO),
-    ("AQ",  Country::AQ),
-    ("AR",  Country::AR),
-    ("AS",  Country::AS),
-    ("AT",  Country::AT),
-    ("AU",  Country::AU),
-    ("AW",  Country::AW),
-    ("AX",  Country::AX),
-    ("AZ",  Country::AZ),
-    ("BA",  Country::BA),
-    ("BB",  Country::BB),
-    ("BD",  Country::BD),
-    ("BE",  Country::BE),
-    ("BF",  Country::BF),
-    ("BG",  Country::BG),
-    ("BH",  Country::BH),
-    ("BI",  Country::BI),
-    ("BJ",  Country::BJ),
-    ("BL",  Country::BL),
-    ("BM",  Country::BM),
-    ("BN",  Country::BN),
-    ("BO",  Country::BO),
-    ("BQ",  Country::BQ),
-    ("BR",  Country::BR),
-    ("BS",  Country::BS),
-    ("BT",  Country::BT),
-    ("BV",  Country::BV),
-    ("BW",  Country::BW),
-    ("BY",  Country::BY),
-    ("BZ",  Country::BZ),
-    ("CA",  Country::CA),
-    ("CC",  Country::CC),
-    ("CD",  Country::CD),
-    ("CF",  Country::CF),
-    ("CG",  Country::CG),
-    ("CH",  Country::CH),
-    ("CI",  Country::CI),
-    ("CK",  Country::CK),
-    ("CL",  Country::CL),
-    ("CM",  Country::CM),
-    ("CN",  Country::CN),
-    ("CO",  Country::CO),
-    ("CR",  Country::CR),
-    ("CU",  Country::CU),
-    ("CV",  Country::CV),
-    ("CW",  Country::CW),
-    ("CX",  Country::CX),
-    ("CY",  Country::CY),
-    ("CZ",  Country::CZ),
-    ("DE",  Country::DE),
-    ("DJ",  Country::DJ),
-    ("DK",  Country::DK),
-    ("DM",  Country::DM),
-    ("DO",  Country::DO),
-    ("DZ",  Country::DZ),
-    ("EC",  Country::EC),
-    ("EE",  Country::EE),
-    ("EG",  Country::EG),
-    ("EH",  Country::EH),
-    ("ER",  Country::ER),
-    ("ES",  Country::ES),
-    ("ET",  Country::ET),
-    ("FI",  Country::FI),
-    ("FJ",  Country::FJ),
-    ("FK",  Country::FK),
-    ("FM",  Country::FM),
-    ("FO",  Country::FO),
-    ("FR",  Country::FR),
-    ("GA",  Country::GA),
-    ("GB",  Country::GB),
-    ("GD",  Country::GD),
-    ("GE",  Country::GE),
-    ("GF",  Country::GF),
-    ("GG",  Country::GG),
-    ("GH",  Country::GH),
-    ("GI",  Country::GI),
-    ("GL",  Country::GL),
-    ("GM",  Country::GM),
-    ("GN",  Country::GN),
-    ("GP",  Country::GP),
-    ("GQ",  Country::GQ),
-    ("GR",  Country::GR),
-    ("GS",  Country::GS),
-    ("GT",  Country::GT),
-    ("GU",  Country::GU),
-    ("GW",  Country::GW),
-    ("GY",  Country::GY),
-    ("HK",  Country::HK),
-    ("HM",  Country::HM),
-    ("HN",  Country::HN),
-    ("HR",  Country::HR),
-    ("HT",  Country::HT),
-    ("HU",  Country::HU),
-    ("ID",  Country::ID),
-    ("IE",  Country::IE),
-    ("IL",  Country::IL),
-    ("IM",  Country::IM),
-    ("IN",  Country::IN),
-    ("IO",  Country::IO),
-    ("IQ",  Country::IQ),
-    ("IR",  Country::IR),
-    ("IS",  Country::IS),
-    ("IT",  Country::IT),
-    ("JE",  Country::JE),
-    ("JM",  Country::JM),
-    ("JO",  Country::JO),
-    ("JP",  Country::JP),
-    ("KE",  Country::KE),
-    ("KG",  Country::KG),
-    ("KH",  Country::KH),
-    ("KI",  Country::KI),
-    ("KM",  Country::KM),
-    ("KN",  Country::KN),
-    ("KP",  Country::KP),
-    ("KR",  Country::KR),
-    ("KW",  Country::KW),
-    ("KY",  Country::KY),
-    ("KZ",  Country::KZ),
-    ("LA",  Country::LA),
-    ("LB",  Country::LB),
-    ("LC",  Country::LC),
-    ("LI",  Country::LI),
-    ("LK",  Country::LK),
-    ("LR",  Country::LR),
-    ("LS",  Country::LS),
-    ("LT",  Country::LT),
-    ("LU",  Country::LU),
-    ("LV",  Country::LV),
-    ("LY",  Country::LY),
-    ("MA",  Country::MA),
-    ("MC",  Country::MC),
-    ("MD",  Country::MD),
-    ("ME",  Country::ME),
-    ("MF",  Country::MF),
-    ("MG",  Country::MG),
-    ("MH",  Country::MH),
-    ("MK",  Country::MK),
-    ("ML",  Country::ML),
-    ("MM",  Country::MM),
-    ("MN",  Country::MN),
-    ("MO",  Country::MO),
-    ("MP",  Country::MP),
-    ("MQ",  Country::MQ),
-    ("MR",  Country::MR),
-    ("MS",  Country::MS),
-    ("MT",  Country::MT),
-    ("MU",  Country::MU),
-    ("MV",  Country::MV),
-    ("MW",  Country::MW),
-    ("MX",  Country::MX),
-    ("MY",  Country::MY),
-    ("MZ",  Country::MZ),
-    ("NA",  Country::NA),
-    ("NC",  Country::NC),
-    ("NE",  Country::NE),
-    ("NF",  Country::NF),
-    ("NG",  Country::NG),
-    ("NI",  Country::NI),
-    ("NL",  Country::NL),
-    ("NO",  Country::NO),
-    ("NP",  Country::NP),
-    ("NR",  Country::NR),
-    ("NU",  Country::NU),
-    ("NZ",  Country::NZ),
-    ("OM",  Country::OM),
-    ("PA",  Country::PA),
-    ("PE",  Country::PE),
-    ("PF",  Country::PF),
-    ("PG",  Country::PG),
-    ("PH",  Country::PH),
-    ("PK",  Country::PK),
-    ("PL",  Country::PL),
-    ("PM",  Country::PM),
-    ("PN",  Country::PN),
-    ("PR",  Country::PR),
-    ("PS",  Country::PS),
-    ("PT",  Country::PT),
-    ("PW",  Country::PW),
-    ("PY",  Country::PY),
-    ("QA",  Country::QA),
-    ("RE",  Country::RE),
-    ("RO",  Country::RO),
-    ("RS",  Country::RS),
-    ("RU",  Country::RU),
-    ("RW",  Country::RW),
-    ("SA",  Country::SA),
-    ("SB",  Country::SB),
-    ("SC",  Country::SC),
-    ("SD",  Country::SD),
-    ("SE",  Country::SE),
-    ("SG",  Country::SG),
-    ("SH",  Country::SH),
-    ("SI",  Country::SI),
-    ("SJ",  Country::SJ),
-    ("SK",  Country::SK),
-    ("SL",  Country::SL),
-    ("SM",  Country::SM),
-    ("SN",  Country::SN),
-    ("SO",  Country::SO),
-    ("SR",  Country::SR),
-    ("SS",  Country::SS),
-    ("ST",  Country::ST),
-    ("SV",  Country::SV),
-    ("SX",  Country::SX),
-    ("SY",  Country::SY),
-    ("SZ",  Country::SZ),
-    ("TC",  Country::TC),
-    ("TD",  Country::TD),
-    ("TF",  Country::TF),
-    ("TG",  Country::TG),
-    ("TH",  Country::TH),
-    ("TJ",  Country::TJ),
-    ("TK",  Country::TK),
-    ("TL",  Country::TL),
-    ("TM",  Country::TM),
-    ("TN",  Country::TN),
-    ("TO",  Country::TO),
-    ("TR",  Country::TR),
-    ("TT",  Country::TT),
-    ("TV",  Country::TV),
-    ("TW",  Country::TW),
-    ("TZ",  Country::TZ),
-    ("UA",  Country::UA),
-    ("UG",  Country::UG),
-    ("UM",  Country::UM),
-    ("US",  Country::US),
-    ("UY",  Country::UY),
-    ("UZ",  Country::UZ),
-    ("VA",  Country::VA),
-    ("VC",  Country::VC),
-    ("VE",  Country::VE),
-    ("VG",  Country::VG),
-    ("VI",  Country::VI),
-    ("VN",  Country::VN),
-    ("VU",  Country::VU),
-    ("WF",  Country::WF),
-    ("WS",  Country::WS),
-    ("YE",  Country::YE),
-    ("YT",  Country::YT),
-    ("ZA",  Country::ZA),
-    ("ZM",  Country::ZM),
-    ("ZW",  Country::ZW),
-];
-
-
 #[cfg(test)]
 mod tests {
     extern crate serde_json;
-    use super::Country;
+    use std::string::ToString;
+    use std::vec::Vec;
+    #[cfg(feature = "data-table")]
+    use super::{ data, CountryFormat };
+    use super::{ Alpha2Code, CodeStatus, Country, ParseErrorKind };
 
     macro_rules! assert_s {
         ($expr:expr) => ({
@@ -1152,11 +421,516 @@ mod tests {
     }
 
     #[test]
+    fn parse_lenient() {
+        assert_eq!(Country::PL, Country::parse_lenient(" pl").unwrap());
+        assert_eq!(Country::PL, Country::parse_lenient("Pl").unwrap());
+        assert_eq!(Country::PL, Country::parse_lenient("pl\n").unwrap());
+        #[cfg(feature = "alpha3")]
+        assert_eq!(Country::DE, Country::parse_lenient("\"deu\",").unwrap());
+        #[cfg(feature = "numeric")]
+        assert_eq!(Country::AF, Country::parse_lenient(" 004 ").unwrap());
+        assert_eq!(Country::Unspecified, Country::parse_lenient("  ").unwrap());
+        assert!(Country::parse_lenient("p l").is_err());
+        #[cfg(feature = "alpha3")]
+        assert!(Country::parse_lenient("pol.").is_ok());
+        assert!(Country::parse_lenient("Poland").is_err());
+        assert!("pl".parse::<Country>().is_err());
+    }
+
+    #[test]
+    fn parse_errors() {
+        let kind = |s: &str| s.parse::<Country>().unwrap_err().kind();
+
+        assert_eq!(ParseErrorKind::InvalidLength, kind("P"));
+        assert_eq!(ParseErrorKind::InvalidLength, kind("Poland"));
+        assert_eq!(ParseErrorKind::NonAscii, kind("ÅL"));
+        assert_eq!(ParseErrorKind::InvalidCharacter, kind("pl"));
+        assert_eq!(ParseErrorKind::InvalidCharacter, kind("P1"));
+        assert_eq!(ParseErrorKind::Unassigned, kind("PO"));
+        assert_eq!(ParseErrorKind::Unassigned, kind("XXX"));
+        assert_eq!(ParseErrorKind::Unassigned, kind("999"));
+        assert_eq!(ParseErrorKind::Reserved, kind("EU"));
+        assert_eq!(ParseErrorKind::Reserved, kind("UK"));
+        assert_eq!(ParseErrorKind::Retired, kind("YU"));
+        assert_eq!(ParseErrorKind::Retired, kind("DD"));
+        assert_eq!(ParseErrorKind::Retired, Country::parse_lenient(" yu ").unwrap_err().kind());
+
+        let err = "PO".parse::<Country>().unwrap_err();
+        assert_eq!(Some("PO"), err.input());
+        assert_eq!("country code must be 2 or 3 characters long, got 6",
+                   "Poland".parse::<Country>().unwrap_err().to_string());
+    }
+
+    #[test]
+    #[cfg(all(feature = "alpha3", feature = "names"))]
+    fn suggestions() {
+        let err = "PO".parse::<Country>().unwrap_err();
+        assert_eq!(vec![Country::PL, Country::PT], err.suggestions().collect::<Vec<_>>());
+        assert_eq!("\"PO\" is not an assigned ISO 3166-1 code; did you mean PL or PT?", err.to_string());
+
+        let err = "pl".parse::<Country>().unwrap_err();
+        assert_eq!(vec![Country::PL, Country::PW], err.suggestions().collect::<Vec<_>>());
+        assert_eq!("GER".parse::<Country>().unwrap_err().suggestions().next(), Some(Country::DE));
+    }
+
+    #[test]
+    #[cfg(feature = "data-table")]
+    fn parse_any() {
+        assert_eq!((Country::DE, CountryFormat::Alpha2), Country::parse_any("DE").unwrap());
+        assert_eq!((Country::DE, CountryFormat::Alpha3), Country::parse_any("DEU").unwrap());
+        assert_eq!((Country::DE, CountryFormat::Numeric), Country::parse_any("276").unwrap());
+        assert_eq!((Country::DE, CountryFormat::Name), Country::parse_any("Germany").unwrap());
+        assert_eq!(ParseErrorKind::Empty, Country::parse_any("").unwrap_err().kind());
+        assert_eq!(ParseErrorKind::Unassigned, Country::parse_any("DX").unwrap_err().kind());
+        assert_eq!(ParseErrorKind::UnknownName, Country::parse_any("Germania").unwrap_err().kind());
+    }
+
+    #[test]
+    #[cfg(feature = "data-table")]
+    fn index_finds_every_form() {
+        for info in data::COUNTRIES {
+            let c = info.country;
+            assert_eq!(Ok((c, CountryFormat::Alpha2)), Country::parse_any(info.alpha2));
+            assert_eq!(Ok((c, CountryFormat::Alpha3)), Country::parse_any(info.alpha3));
+            assert_eq!(Ok((c, CountryFormat::Numeric)), Country::parse_any(c.numeric_str()));
+            assert_eq!(Ok((c, CountryFormat::Name)), Country::parse_any(info.name));
+        }
+
+        assert_eq!(None, Country::from_alpha2("POL"));
+        assert_eq!(None, Country::from_alpha3("616"));
+        assert_eq!(None, Country::from_name("PL"));
+        assert_eq!(None, Country::from_code("Poland"));
+        assert_eq!(Some(Country::GB), Country::from_name("United Kingdom of Great Britain"));
+    }
+
+    #[test]
+    fn parse_bytes() {
+        assert_eq!(Ok(Country::PL), Country::from_bytes(b"PL"));
+        #[cfg(feature = "alpha3")]
+        assert_eq!(Ok(Country::PL), Country::from_bytes(b"POL"));
+        #[cfg(feature = "numeric")]
+        assert_eq!(Ok(Country::PL), Country::from_bytes(b"616"));
+        assert_eq!(Ok(Country::Unspecified), Country::from_bytes(b""));
+        assert_eq!(ParseErrorKind::InvalidLength, Country::from_bytes(b"Poland").unwrap_err().kind());
+        assert_eq!(ParseErrorKind::NonAscii, Country::from_bytes(b"\xff\xfe").unwrap_err().kind());
+        assert_eq!(Some("PO"), Country::from_bytes(b"PO").unwrap_err().input());
+
+        let column: Vec<_> = Country::parse_column(b"PL,,FR,xx", b',').collect();
+        assert_eq!(4, column.len());
+        assert_eq!(Ok(Country::Unspecified), column[1]);
+        assert_eq!(ParseErrorKind::InvalidCharacter, column[3].unwrap_err().kind());
+        assert_eq!(0, Country::parse_column(b"", b'\n').count());
+        assert_eq!(2, Country::parse_column(b"PL\nDE\n", b'\n').count());
+
+        let crlf: Vec<_> = Country::parse_column(b"PL\r\nDE\r\n\r\nFR", b'\n').collect();
+        assert_eq!(vec![Ok(Country::PL), Ok(Country::DE), Ok(Country::Unspecified), Ok(Country::FR)], crlf);
+        assert!(Country::parse_column(b"PL\r,DE", b',').next().unwrap().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn parse_many() {
+        let fields: &[&[u8]] = &[b"PL", b"XX", b"DE"];
+        let parsed = Country::parse_many(fields);
+        assert_eq!(Ok(Country::PL), parsed[0]);
+        assert_eq!(ParseErrorKind::Unassigned, parsed[1].unwrap_err().kind());
+        assert_eq!(Ok(Country::DE), parsed[2]);
+    }
+
+    #[test]
+    #[cfg(feature = "names")]
+    fn names() {
+        assert_eq!("Bolivia (Plurinational State of)", Country::BO.name());
+        assert_eq!("Plurinational State of Bolivia", Country::BO.name_official());
+        assert_eq!("Republic of Poland", Country::PL.name_official());
+        assert_eq!("Tanzania, United Republic of", Country::TZ.name());
+        assert_eq!("Netherlands", Country::NL.name());
+        assert_eq!("Turkey", Country::TR.name());
+        assert_eq!("Aruba", Country::AW.name_official());
+        assert_eq!("", Country::Unspecified.name_official());
+
+        assert_eq!(Some(Country::KR), Country::from_name("Republic of Korea"));
+        assert_eq!(Some(Country::KR), Country::from_name("Korea, Republic of"));
+        assert_eq!(Some(Country::CZ), Country::from_name("Czech Republic"));
+        assert_eq!(Some(Country::TR), Country::from_name("Türkiye"));
+        assert_eq!(Some(Country::NL), Country::from_name("Netherlands (Kingdom of the)"));
+    }
+
+    #[test]
+    #[cfg(feature = "names")]
+    fn localised_names() {
+        use super::Language;
+
+        assert_eq!(Some(Language::French), Language::from_code("fr"));
+        assert_eq!(Some(Language::French), Language::from_code("FR-ca"));
+        assert_eq!(Some(Language::Spanish), Language::from_code("es_MX"));
+        assert_eq!(None, Language::from_code("xx"));
+
+        assert_eq!("Poland", Country::PL.name_in(Language::English));
+        assert_eq!("", Country::Unspecified.name_in(Language::French));
+        for &lang in Language::ALL {
+            let expected = match lang {
+                Language::French if cfg!(feature = "names-fr") => "Pologne",
+                Language::German if cfg!(feature = "names-de") => "Polen",
+                Language::Spanish if cfg!(feature = "names-es") => "Polonia",
+                _ => "Poland",
+            };
+            assert_eq!(expected, Country::PL.name_in(lang), "{:?}", lang);
+            assert_eq!(expected != "Poland" || lang == Language::English, lang.is_available());
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "endonyms")]
+    fn endonyms() {
+        let names = |c: Country| c.endonyms().iter().map(|e| e.name).collect::<Vec<_>>();
+
+        assert_eq!(vec!["Polska"], names(Country::PL));
+        assert_eq!(vec!["日本"], names(Country::JP));
+        assert_eq!(vec!["Schweiz", "Suisse", "Svizzera", "Svizra"], names(Country::CH));
+        assert_eq!(vec!["België", "Belgique", "Belgien"], names(Country::BE));
+        assert!(Country::AQ.endonyms().is_empty());
+        assert!(Country::Unspecified.endonyms().is_empty());
+
+        let greek = Country::GR.endonyms()[0];
+        assert_eq!(("Ελλάδα", "el", "Grek"), (greek.name, greek.language, greek.script));
+        assert_eq!("Cyrl", Country::RS.endonyms()[0].script);
+        assert_eq!("Latn", Country::RS.endonyms()[1].script);
+    }
+
+    #[test]
+    #[cfg(feature = "names")]
+    fn aliases() {
+        use super::NameMatch;
+
+        assert_eq!(Some((Country::GB, NameMatch::Alias)), Country::from_name_or_alias("United Kingdom"));
+        assert_eq!(Some((Country::GB, NameMatch::Alias)), Country::from_name_or_alias("UK"));
+        assert_eq!(Some((Country::NL, NameMatch::Alias)), Country::from_name_or_alias("Holland"));
+        assert_eq!(Some((Country::CI, NameMatch::Alias)), Country::from_name_or_alias("Ivory Coast"));
+        assert_eq!(Some((Country::RU, NameMatch::Canonical)), Country::from_name_or_alias("Russian Federation"));
+        assert_eq!(Some((Country::CZ, NameMatch::Canonical)), Country::from_name_or_alias("Czechia"));
+        assert_eq!(Some((Country::US, NameMatch::Alias)), Country::from_name_or_alias("USA"));
+        assert_eq!(None, Country::from_name_or_alias("GB"));
+        assert_eq!(None, Country::from_name_or_alias("POL"));
+        assert_eq!(None, Country::from_name_or_alias("Korea"));
+        assert_eq!(None, Country::from_name_or_alias("Atlantis"));
+
+        assert_eq!(None, Country::from_name("Russia"));
+        assert_eq!(None, Country::from_name("USA"));
+        assert!("UK".parse::<Country>().is_err());
+        #[cfg(feature = "alpha3")]
+        assert_eq!(Ok((Country::US, super::CountryFormat::Alpha3)), Country::parse_any("USA"));
+        assert!(Country::parse_any("Holland").is_err());
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "names"))]
+    fn search() {
+        assert_eq!(Country::PH, Country::search("Phillipines")[0].0);
+        assert_eq!(Country::CO, Country::search("Columbia")[0].0);
+        assert_eq!(Country::KZ, Country::search("Kazakstan")[0].0);
+        assert_eq!(Country::KR, Country::search("korea south")[0].0);
+        assert_eq!(Country::GB, Country::search("great britian")[0].0);
+        assert_eq!((Country::PL, 1.0), Country::search(" POLAND! ")[0]);
+        assert!(Country::search("Poland")[1..].iter().all(|&(_, score)| score < 1.0));
+        assert!(Country::search("Congo").iter().any(|&(c, _)| c == Country::CD));
+        assert_eq!(Country::US, Country::search("usa")[0].0);
+
+        let mut korea: Vec<_> = Country::search("Korea").iter().take(2).map(|&(c, _)| c).collect();
+        korea.sort();
+        assert_eq!(vec![Country::KP, Country::KR], korea);
+        assert!(Country::search("Atlantis").is_empty());
+        assert!(Country::search("").is_empty());
+
+        let results = Country::search("Nigeria");
+        assert_eq!(Country::NG, results[0].0);
+        assert!(results.windows(2).all(|w| w[0].1 >= w[1].1));
+    }
+
+    #[test]
+    fn code_status() {
+        let status = |s: &str| s.parse::<Alpha2Code>().unwrap().status();
+
+        assert_eq!(CodeStatus::Assigned(Country::PL), status("PL"));
+        assert_eq!(CodeStatus::ExceptionallyReserved, status("EU"));
+        assert_eq!(CodeStatus::ExceptionallyReserved, status("uk"));
+        assert_eq!(CodeStatus::TransitionallyReserved, status("YU"));
+        assert_eq!(CodeStatus::IndeterminatelyReserved, status("RC"));
+        assert_eq!(CodeStatus::FormerlyUsed, status("DD"));
+        assert_eq!(CodeStatus::UserAssigned, status("XK"));
+        assert_eq!(CodeStatus::UserAssigned, status("QM"));
+        assert_eq!(CodeStatus::UserAssigned, status("ZZ"));
+        assert_eq!(CodeStatus::Unassigned, status("QL"));
+        assert!(status("EZ").is_reserved());
+
+        let xk: Alpha2Code = "XK".parse().unwrap();
+        #[cfg(feature = "names")]
+        assert_eq!(Some("Kosovo"), xk.name());
+        assert_eq!(None, xk.country());
+        assert_eq!("XK", xk.to_string());
+        #[cfg(feature = "names")]
+        assert_eq!(Some("European Union"), "EU".parse::<Alpha2Code>().unwrap().name());
+        assert_eq!(Alpha2Code::from_country(Country::PL), Some("PL".parse().unwrap()));
+        assert_eq!(ParseErrorKind::InvalidCharacter, "X1".parse::<Alpha2Code>().unwrap_err().kind());
+        assert_eq!(ParseErrorKind::InvalidLength, "XKX".parse::<Alpha2Code>().unwrap_err().kind());
+    }
+
+    #[test]
+    fn normalize() {
+        assert_eq!(Country::GB, Country::from_alpha2_normalized("UK").unwrap());
+        assert_eq!(Country::GR, Country::from_alpha2_normalized("el").unwrap());
+        assert_eq!(Country::PL, Country::from_alpha2_normalized("PL").unwrap());
+        assert_eq!(ParseErrorKind::Reserved, Country::from_alpha2_normalized("EU").unwrap_err().kind());
+        assert_eq!(Some(Country::GB), "UK".parse::<Country>().unwrap_err().suggestions().next());
+    }
+
+    #[test]
+    fn all() {
+        assert_eq!(249, Country::COUNT);
+        assert_eq!(Country::COUNT, Country::iter().len());
+        assert_eq!(Some(&Country::AD), Country::ALL.first());
+        assert_eq!(Some(&Country::ZW), Country::ALL.last());
+        assert!(!Country::ALL.contains(&Country::Unspecified));
+
+        for (i, country) in Country::iter().enumerate() {
+            assert_eq!(Some(i), country.index());
+            assert_eq!(Some(country), Country::from_index(i));
+        }
+
+        assert_eq!(None, Country::Unspecified.index());
+        assert_eq!(None, Country::from_index(Country::COUNT));
+    }
+
+    #[test]
+    fn const_lookup() {
+        const PL: Country = country!("PL");
+        const TABLE: [Option<Country>; 2] = [
+            Country::from_alpha2("DE"),
+            Country::from_code("XX"),
+        ];
+
+        assert_eq!(Country::PL, PL);
+        assert_eq!([Some(Country::DE), None], TABLE);
+        assert_eq!(None, Country::from_code(""));
+        assert_eq!(None, Country::from_alpha2(""));
+
+        for &country in Country::ALL {
+            assert_eq!(Some(country), Country::from_alpha2(country.alpha2()));
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "alpha3", feature = "numeric"))]
+    fn const_lookup_alpha3_numeric() {
+        const TABLE: [Option<Country>; 3] = [
+            Country::from_alpha3("FRA"),
+            Country::from_numeric(4),
+            Country::from_code("DEU"),
+        ];
+
+        assert_eq!([Some(Country::FR), Some(Country::AF), Some(Country::DE)], TABLE);
+        assert_eq!(None, Country::from_numeric_str("4a0"));
+
+        for &country in Country::ALL {
+            assert_eq!(Some(country), Country::from_alpha3(country.alpha3()));
+            assert_eq!(Some(country), Country::from_numeric(country.numeric()));
+        }
+    }
+
+    #[test]
+    fn conversions() {
+        use core::convert::TryFrom;
+
+        assert_eq!(Country::PL, Country::try_from("PL").unwrap());
+        assert_eq!(Country::PL, Country::try_from(*b"PL").unwrap());
+        assert_eq!(616u16, u16::from(Country::PL));
+        assert_eq!(0, u16::from(Country::Unspecified));
+
+        #[cfg(all(feature = "alpha3", feature = "numeric"))]
+        {
+            assert_eq!(Country::PL, Country::try_from("POL").unwrap());
+            assert_eq!(Country::PL, Country::try_from(616).unwrap());
+            assert_eq!(Country::PL, Country::try_from(&b"616"[..]).unwrap());
+
+            let err = Country::try_from(999).unwrap_err();
+            assert_eq!((ParseErrorKind::Unassigned, Some("999")), (err.kind(), err.input()));
+            let err = Country::try_from(7).unwrap_err();
+            assert_eq!(Some("007"), err.input());
+            assert_eq!(ParseErrorKind::InvalidLength, Country::try_from(1000).unwrap_err().kind());
+        }
+        assert_eq!(ParseErrorKind::NonAscii, Country::try_from(&b"\xc3\x85L"[..]).unwrap_err().kind());
+        assert_eq!(ParseErrorKind::Unassigned, Country::try_from(*b"XX").unwrap_err().kind());
+
+        assert_eq!("PL", Country::PL.as_ref());
+        assert_eq!("PL", Country::PL.alpha2());
+        assert!("PL" == Country::PL);
+        assert!("POL" != Country::PL);
+        assert!(*"PL" == Country::PL);
+        assert_eq!("PL", Country::PL);
+    }
+
+    #[test]
+    #[cfg(feature = "names")]
     fn name() {
         assert_eq!("Poland", Country::PL.name());
         assert_eq!("", Country::Unspecified.name());
     }
 
+    #[test]
+    #[cfg(feature = "data-table")]
+    fn from_name() {
+        assert_eq!(Some(Country::PL), Country::from_name("Poland"));
+        assert_eq!(Some(Country::CZ), Country::from_name("Czechia"));
+        assert_eq!(Some(Country::CZ), Country::from_name("Czech Republic"));
+        assert_eq!(Some(Country::GB), Country::from_name("United Kingdom of Great Britain"));
+        assert_eq!(None, Country::from_name("poland"));
+        assert_eq!(None, Country::from_name(""));
+
+        for info in data::COUNTRIES {
+            assert_eq!(Some(info.country), Country::from_name(info.name));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alpha3")]
+    fn alpha3() {
+        assert_eq!("POL", Country::PL.alpha3());
+        assert_eq!("DEU", Country::DE.alpha3());
+        assert_eq!("", Country::Unspecified.alpha3());
+        assert_eq!(Some(Country::PL), Country::from_alpha3("POL"));
+        assert_eq!(None, Country::from_alpha3("PL"));
+        assert_eq!(None, Country::from_alpha3("XXX"));
+        assert_eq!(Country::DE, "DEU".parse().unwrap());
+        assert!("DE ".parse::<Country>().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "numeric")]
+    fn numeric() {
+        assert_eq!(616, Country::PL.numeric());
+        assert_eq!(4, Country::AF.numeric());
+        assert_eq!("004", Country::AF.numeric_str());
+        assert_eq!("616", Country::PL.numeric_str());
+        assert_eq!(Some(Country::AF), Country::from_numeric(4));
+        assert_eq!(None, Country::from_numeric(0));
+        assert_eq!(None, Country::from_numeric(999));
+        assert_eq!(Some(Country::AF), Country::from_numeric_str("004"));
+        assert_eq!(None, Country::from_numeric_str("4"));
+        assert_eq!(Country::PL, "616".parse().unwrap());
+        assert!("000".parse::<Country>().is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "data-table")]
+    fn info() {
+        let info = Country::PL.info().unwrap();
+        assert_eq!(Country::PL, info.country);
+        assert_eq!("PL", info.alpha2);
+        assert_eq!("POL", info.alpha3);
+        assert_eq!(616, info.numeric);
+        assert_eq!("Poland", info.name);
+        assert!(Country::Unspecified.info().is_none());
+
+        for info in data::COUNTRIES {
+            assert_eq!(Some(info), info.country.info());
+            assert_eq!(info.country, info.alpha2.parse().unwrap());
+            assert_eq!(info.numeric, info.country.numeric());
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "std", feature = "data-table"))]
+    #[allow(deprecated)]
+    fn all_by_name() {
+        let codes = data::all();
+        assert_eq!(Country::COUNT, codes.len());
+        let first: Vec<_> = codes.iter().take(4).map(|c| c.alpha2).collect();
+        assert_eq!(vec!["AF", "AX", "AL", "DZ"], first);
+        assert_eq!("ZW", codes.last().unwrap().alpha2);
+        assert_eq!("004", codes[0].num);
+    }
+
+    #[test]
+    fn parse_strict() {
+        assert_eq!(Country::PL, Country::parse_strict("PL").unwrap());
+        assert_eq!(ParseErrorKind::Empty, Country::parse_strict("").unwrap_err().kind());
+        assert_eq!(Country::Unspecified, "".parse().unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn strict_serde() {
+        use with::{ optional, strict };
+
+        let de = |json: &str| strict::deserialize(&mut serde_json::Deserializer::from_str(json));
+        assert_eq!(Country::PL, de("\"PL\"").unwrap());
+        assert!(de("\"\"").is_err());
+
+        let de = |json: &str| optional::deserialize(&mut serde_json::Deserializer::from_str(json));
+        assert_eq!(Some(Country::PL), de("\"PL\"").unwrap());
+        assert_eq!(None, de("\"\"").unwrap());
+        assert_eq!(None, de("null").unwrap());
+        assert!(de("\"XX\"").is_err());
+
+        let mut out = Vec::new();
+        assert!(strict::serialize(&Country::Unspecified, &mut serde_json::Serializer::new(&mut out)).is_err());
+        optional::serialize(&None, &mut serde_json::Serializer::new(&mut out)).unwrap();
+        assert_eq!(b"null", &out[..]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "data-table"))]
+    fn serde_representations() {
+        use with::{ alpha3, name, numeric, numeric_str };
+
+        macro_rules! round_trip {
+            ($module:ident, $country:expr, $json:expr) => ({
+                let mut out = Vec::new();
+                $module::serialize(&$country, &mut serde_json::Serializer::new(&mut out)).unwrap();
+                assert_eq!($json, std::str::from_utf8(&out).unwrap());
+                let de = $module::deserialize(&mut serde_json::Deserializer::from_str($json)).unwrap();
+                assert_eq!($country, de);
+            })
+        }
+
+        round_trip!(alpha3, Country::PL, "\"POL\"");
+        round_trip!(numeric, Country::PL, "616");
+        round_trip!(numeric, Country::AF, "4");
+        round_trip!(numeric_str, Country::AF, "\"004\"");
+        round_trip!(name, Country::PL, "\"Poland\"");
+        round_trip!(alpha3, Country::Unspecified, "\"\"");
+
+        let de = |json: &str| numeric::deserialize(&mut serde_json::Deserializer::from_str(json));
+        assert!(de("999").is_err());
+        assert!(de("65552").is_err());
+        assert!(de("-4").is_err());
+        assert!(alpha3::deserialize(&mut serde_json::Deserializer::from_str("\"PL\"")).is_err());
+    }
+
+    #[test]
+    #[cfg(all(feature = "serde", feature = "data-table"))]
+    fn deserializes_leniently() {
+        let de = |json: &str| ::with::lenient::deserialize(&mut serde_json::Deserializer::from_str(json));
+
+        for json in &["616", "\"616\"", "\"PL\"", "\"pl\"", "\"POL\"", "\" pol \""] {
+            assert_eq!(Country::PL, de(json).unwrap(), "{}", json);
+        }
+        assert_eq!(Country::AF, de("4").unwrap());
+        assert_eq!(Country::AF, de("\"4\"").unwrap());
+        assert_eq!(Country::Unspecified, de("\"\"").unwrap());
+
+        assert_eq!("invalid value: integer `999`, expected a numeric country code at line 1 column 3",
+                   de("999").unwrap_err().to_string());
+        assert_eq!("invalid value: integer `-1`, expected a numeric country code at line 1 column 2",
+                   de("-1").unwrap_err().to_string());
+        assert_eq!("invalid value: string \"po\", expected an alpha-2, alpha-3 or numeric country code; \
+                    did you mean PL or PT? at line 1 column 4",
+                   de("\"po\"").unwrap_err().to_string());
+        assert_eq!("invalid value: string \" xx \", expected an alpha-2, alpha-3 or numeric country code at line 1 column 6",
+                   de("\" xx \"").unwrap_err().to_string());
+        assert!(de("true").unwrap_err().to_string().starts_with("invalid type: boolean `true`"));
+    }
+
     #[test]
     #[cfg(feature = "serde")]
     fn serializes() {
@@ -1173,5 +947,9 @@ mod tests {
         assert_eq!(Country::PL, serde_json::from_slice(b"\"PL\"").unwrap());
         assert_eq!(Country::ES, serde_json::from_slice(b"\"ES\"").unwrap());
         assert_eq!(Country::IT, serde_json::from_slice(b"\"IT\"").unwrap());
+
+        let err = serde_json::from_slice::<Country>(b"\"POLAND\"").unwrap_err();
+        assert_eq!("invalid value: string \"POLAND\", expected an alpha-2, alpha-3 or numeric country code at line 1 column 8",
+                   err.to_string());
     }
 }
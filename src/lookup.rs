@@ -0,0 +1,97 @@
+// `const fn` lookups over the generated tables, usable both at run time and
+// in constant expressions such as the `country!` macro.
+
+use hash;
+use { Country, CountryFormat, INDEX_DISPLACEMENTS, INDEX_ENTRIES, INDEX_SLOTS };
+
+const fn eq_bytes(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < a.len() {
+        if hash::word(a, i) != hash::word(b, i) {
+            return false;
+        }
+        i += 8;
+    }
+
+    true
+}
+
+/// Looks up an alpha-2, alpha-3 or numeric code or a name in the perfect-hash
+/// index, reporting which of them matched. Keys are case-sensitive. Aliases
+/// are left out, see `search_name`.
+pub(crate) const fn search(key: &str) -> Option<(Country, CountryFormat)> {
+    search_bytes(key.as_bytes())
+}
+
+pub(crate) const fn search_bytes(key: &[u8]) -> Option<(Country, CountryFormat)> {
+    match entry(key) {
+        Some((_, CountryFormat::Name, true)) | None => None,
+        Some((country, format, _)) => Some((country, format)),
+    }
+}
+
+/// Looks up a name or an alias, reporting whether it was an alias. Codes are
+/// only found when they double as an alias, like `"USA"`.
+#[cfg(feature = "names")]
+pub(crate) const fn search_name(key: &str) -> Option<(Country, bool)> {
+    match entry(key.as_bytes()) {
+        Some((country, CountryFormat::Name, alias)) => Some((country, alias)),
+        Some((country, _, true)) => Some((country, true)),
+        _ => None,
+    }
+}
+
+const fn entry(key: &[u8]) -> Option<(Country, CountryFormat, bool)> {
+    let h = hash::hash(key);
+    let d = INDEX_DISPLACEMENTS[hash::bucket(h, INDEX_DISPLACEMENTS.len())];
+    let i = INDEX_SLOTS[hash::slot(h, d, INDEX_SLOTS.len())];
+    if i == u16::MAX {
+        return None;
+    }
+
+    let (found, country, format, alias) = INDEX_ENTRIES[i as usize];
+    if eq_bytes(found.as_bytes(), key) {
+        Some((country, format, alias))
+    } else {
+        None
+    }
+}
+
+#[cfg(feature = "numeric")]
+pub(crate) const fn search_numeric(table: &[(u16, Country)], key: u16) -> Option<Country> {
+    let (mut lo, mut hi) = (0, table.len());
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if table[mid].0 < key {
+            lo = mid + 1;
+        } else if table[mid].0 > key {
+            hi = mid;
+        } else {
+            return Some(table[mid].1);
+        }
+    }
+
+    None
+}
+
+/// Number of slots in `CountryMap` and `CountrySet`: one per country plus
+/// slot 0 for `Country::Unspecified`, so that slots follow code order.
+pub(crate) const SLOTS: usize = Country::COUNT + 1;
+
+pub(crate) const fn slot(country: Country) -> usize {
+    match country.index() {
+        Some(i) => i + 1,
+        None => 0,
+    }
+}
+
+pub(crate) const fn country_at(slot: usize) -> Country {
+    match slot {
+        0 => Country::Unspecified,
+        _ => Country::ALL[slot - 1],
+    }
+}
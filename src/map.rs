@@ -0,0 +1,355 @@
+use core::{ array, fmt, iter, ops, slice };
+
+use lookup::{ country_at, slot, SLOTS };
+use Country;
+
+/// A map keyed by `Country`, backed by an array with one slot per country.
+///
+/// Lookups are plain array indexing and iteration is in alpha-2 order.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct CountryMap<V> {
+    slots: [Option<V>; SLOTS],
+    len: usize,
+}
+
+impl<V> CountryMap<V> {
+    pub fn new() -> CountryMap<V> {
+        CountryMap { slots: array::from_fn(|_| None), len: 0 }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn contains_key(&self, country: Country) -> bool {
+        self.slots[slot(country)].is_some()
+    }
+
+    pub fn get(&self, country: Country) -> Option<&V> {
+        self.slots[slot(country)].as_ref()
+    }
+
+    pub fn get_mut(&mut self, country: Country) -> Option<&mut V> {
+        self.slots[slot(country)].as_mut()
+    }
+
+    pub fn insert(&mut self, country: Country, value: V) -> Option<V> {
+        let old = self.slots[slot(country)].replace(value);
+        if old.is_none() {
+            self.len += 1;
+        }
+        old
+    }
+
+    pub fn remove(&mut self, country: Country) -> Option<V> {
+        let old = self.slots[slot(country)].take();
+        if old.is_some() {
+            self.len -= 1;
+        }
+        old
+    }
+
+    pub fn clear(&mut self) {
+        for value in self.slots.iter_mut() {
+            *value = None;
+        }
+        self.len = 0;
+    }
+
+    pub fn entry(&mut self, country: Country) -> Entry<'_, V> {
+        Entry { country, value: &mut self.slots[slot(country)], len: &mut self.len }
+    }
+
+    pub fn iter(&self) -> Iter<'_, V> {
+        Iter { inner: self.slots.iter().enumerate(), len: self.len }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, V> {
+        IterMut { inner: self.slots.iter_mut().enumerate(), len: self.len }
+    }
+
+    pub fn keys<'a>(&'a self) -> impl Iterator<Item = Country> + 'a {
+        self.iter().map(|(country, _)| country)
+    }
+
+    pub fn values<'a>(&'a self) -> impl Iterator<Item = &'a V> + 'a {
+        self.iter().map(|(_, value)| value)
+    }
+
+    pub fn values_mut<'a>(&'a mut self) -> impl Iterator<Item = &'a mut V> + 'a {
+        self.iter_mut().map(|(_, value)| value)
+    }
+}
+
+impl<V> Default for CountryMap<V> {
+    fn default() -> CountryMap<V> {
+        CountryMap::new()
+    }
+}
+
+impl<V: fmt::Debug> fmt::Debug for CountryMap<V> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<V> ops::Index<Country> for CountryMap<V> {
+    type Output = V;
+
+    /// Panics if there is no value for `country`.
+    fn index(&self, country: Country) -> &V {
+        self.get(country).expect("no entry for country in CountryMap")
+    }
+}
+
+impl<V> ops::IndexMut<Country> for CountryMap<V> {
+    fn index_mut(&mut self, country: Country) -> &mut V {
+        self.get_mut(country).expect("no entry for country in CountryMap")
+    }
+}
+
+impl<V> iter::FromIterator<(Country, V)> for CountryMap<V> {
+    fn from_iter<I: IntoIterator<Item = (Country, V)>>(iter: I) -> CountryMap<V> {
+        let mut map = CountryMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<V> Extend<(Country, V)> for CountryMap<V> {
+    fn extend<I: IntoIterator<Item = (Country, V)>>(&mut self, iter: I) {
+        for (country, value) in iter {
+            self.insert(country, value);
+        }
+    }
+}
+
+impl<'a, V> IntoIterator for &'a CountryMap<V> {
+    type Item = (Country, &'a V);
+    type IntoIter = Iter<'a, V>;
+
+    fn into_iter(self) -> Iter<'a, V> {
+        self.iter()
+    }
+}
+
+impl<'a, V> IntoIterator for &'a mut CountryMap<V> {
+    type Item = (Country, &'a mut V);
+    type IntoIter = IterMut<'a, V>;
+
+    fn into_iter(self) -> IterMut<'a, V> {
+        self.iter_mut()
+    }
+}
+
+impl<V> IntoIterator for CountryMap<V> {
+    type Item = (Country, V);
+    type IntoIter = IntoIter<V>;
+
+    fn into_iter(self) -> IntoIter<V> {
+        IntoIter { inner: IntoIterator::into_iter(self.slots).enumerate(), len: self.len }
+    }
+}
+
+/// A view into a single slot of a `CountryMap`, which may be empty.
+pub struct Entry<'a, V: 'a> {
+    country: Country,
+    value: &'a mut Option<V>,
+    len: &'a mut usize,
+}
+
+impl<'a, V> Entry<'a, V> {
+    pub fn key(&self) -> Country {
+        self.country
+    }
+
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> &'a mut V {
+        if self.value.is_none() {
+            *self.len += 1;
+        }
+        self.value.get_or_insert_with(default)
+    }
+
+    pub fn or_default(self) -> &'a mut V where V: Default {
+        self.or_insert_with(V::default)
+    }
+
+    pub fn and_modify<F: FnOnce(&mut V)>(self, f: F) -> Entry<'a, V> {
+        if let Some(ref mut value) = *self.value {
+            f(value);
+        }
+        self
+    }
+}
+
+macro_rules! slot_iter {
+    ($name:ident, $item:ty, $($lt:lifetime)*) => {
+        impl<$($lt,)* V> Iterator for $name<$($lt,)* V> {
+            type Item = $item;
+
+            fn next(&mut self) -> Option<$item> {
+                for (slot, value) in &mut self.inner {
+                    if let Some(value) = value {
+                        self.len -= 1;
+                        return Some((country_at(slot), value));
+                    }
+                }
+                None
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                (self.len, Some(self.len))
+            }
+        }
+
+        impl<$($lt,)* V> ExactSizeIterator for $name<$($lt,)* V> {}
+
+        impl<$($lt,)* V> iter::FusedIterator for $name<$($lt,)* V> {}
+    };
+}
+
+/// Iterator over the entries of a `CountryMap`, in alpha-2 order.
+pub struct Iter<'a, V: 'a> {
+    inner: iter::Enumerate<slice::Iter<'a, Option<V>>>,
+    len: usize,
+}
+
+impl<'a, V> Clone for Iter<'a, V> {
+    fn clone(&self) -> Self {
+        Iter { inner: self.inner.clone(), len: self.len }
+    }
+}
+
+slot_iter!(Iter, (Country, &'a V), 'a);
+
+/// Mutable iterator over the entries of a `CountryMap`, in alpha-2 order.
+pub struct IterMut<'a, V: 'a> {
+    inner: iter::Enumerate<slice::IterMut<'a, Option<V>>>,
+    len: usize,
+}
+
+slot_iter!(IterMut, (Country, &'a mut V), 'a);
+
+/// Owning iterator over the entries of a `CountryMap`, in alpha-2 order.
+pub struct IntoIter<V> {
+    inner: iter::Enumerate<array::IntoIter<Option<V>, SLOTS>>,
+    len: usize,
+}
+
+slot_iter!(IntoIter, (Country, V), );
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use core::fmt;
+    use core::marker::PhantomData;
+    use serde::{ Deserialize, Deserializer, Serialize, Serializer };
+    use serde::de::{ MapAccess, Visitor };
+
+    use super::CountryMap;
+    use Country;
+
+    /// Serializes as a map keyed by alpha-2 code.
+    impl<V: Serialize> Serialize for CountryMap<V> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+            serializer.collect_map(self.iter().map(|(country, value)| (country.alpha2(), value)))
+        }
+    }
+
+    impl<'de, V: Deserialize<'de>> Deserialize<'de> for CountryMap<V> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+            struct MapVisitor<V>(PhantomData<V>);
+
+            impl<'de, V: Deserialize<'de>> Visitor<'de> for MapVisitor<V> {
+                type Value = CountryMap<V>;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    formatter.write_str("a map keyed by country code")
+                }
+
+                fn visit_map<A>(self, mut access: A) -> Result<CountryMap<V>, A::Error> where A: MapAccess<'de> {
+                    let mut map = CountryMap::new();
+                    while let Some((country, value)) = access.next_entry::<Country, V>()? {
+                        map.insert(country, value);
+                    }
+                    Ok(map)
+                }
+            }
+
+            deserializer.deserialize_map(MapVisitor(PhantomData))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate serde_json;
+    use std::vec::Vec;
+    use super::CountryMap;
+    use Country;
+
+    #[test]
+    fn insert_get_remove() {
+        let mut map = CountryMap::new();
+        assert!(map.is_empty());
+        assert_eq!(None, map.insert(Country::PL, 23));
+        assert_eq!(Some(23), map.insert(Country::PL, 24));
+        map.insert(Country::DE, 19);
+
+        assert_eq!(2, map.len());
+        assert_eq!(Some(&24), map.get(Country::PL));
+        assert_eq!(19, map[Country::DE]);
+        assert!(!map.contains_key(Country::FR));
+
+        assert_eq!(Some(19), map.remove(Country::DE));
+        assert_eq!(None, map.remove(Country::DE));
+        assert_eq!(1, map.len());
+
+        map.clear();
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn entry() {
+        let mut map: CountryMap<u32> = CountryMap::new();
+        *map.entry(Country::PL).or_insert(0) += 1;
+        *map.entry(Country::PL).or_default() += 1;
+        map.entry(Country::DE).and_modify(|v| *v += 1).or_insert_with(|| 10);
+        map.entry(Country::DE).and_modify(|v| *v += 1);
+
+        assert_eq!(Some(&2), map.get(Country::PL));
+        assert_eq!(Some(&11), map.get(Country::DE));
+        assert_eq!(2, map.len());
+    }
+
+    #[test]
+    fn iterates_in_code_order() {
+        let map: CountryMap<&str> = vec![(Country::ZW, "zw"), (Country::AD, "ad"), (Country::Unspecified, "")]
+            .into_iter()
+            .collect();
+
+        let keys: Vec<_> = map.keys().collect();
+        assert_eq!(vec![Country::Unspecified, Country::AD, Country::ZW], keys);
+        assert_eq!(3, map.iter().len());
+
+        let owned: Vec<_> = map.into_iter().collect();
+        assert_eq!(vec![(Country::Unspecified, ""), (Country::AD, "ad"), (Country::ZW, "zw")], owned);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let map: CountryMap<u8> = vec![(Country::PL, 23), (Country::DE, 19)].into_iter().collect();
+        let json = serde_json::to_string(&map).unwrap();
+        assert_eq!(r#"{"DE":19,"PL":23}"#, json);
+        assert_eq!(map, serde_json::from_str::<CountryMap<u8>>(&json).unwrap());
+        assert!(serde_json::from_str::<CountryMap<u8>>(r#"{"XX":1}"#).is_err());
+    }
+}
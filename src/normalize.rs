@@ -0,0 +1,22 @@
+// Name normalisation for `Country::search`. build.rs includes this file to
+// normalise every name ahead of time, so it must not depend on the rest of
+// the crate.
+
+use std::string::String;
+
+/// Lower-cases `s` and turns punctuation into single spaces, so that
+/// `"Korea, Republic of"` becomes `"korea republic of"`.
+pub fn normalize(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars().flat_map(char::to_lowercase) {
+        if c.is_alphanumeric() {
+            out.push(c);
+        } else if !out.is_empty() && !out.ends_with(' ') {
+            out.push(' ');
+        }
+    }
+    if out.ends_with(' ') {
+        out.pop();
+    }
+    out
+}
@@ -0,0 +1,181 @@
+// Ranked fuzzy matching of user-entered names against every name and alias.
+// build.rs normalises the keys, so a search only normalises the query, and
+// the buffers below are reused from one key to the next.
+
+use std::mem;
+use std::string::String;
+use std::vec::Vec;
+
+use normalize::normalize;
+use { Country, CountryMap, SEARCH_KEYS };
+
+/// Results scoring below this are left out.
+const MIN_SCORE: f32 = 0.6;
+
+/// Words too common in country names to say anything about a match.
+const STOP_WORDS: &[&str] = &["and", "of", "the"];
+
+/// The last three rows of the edit distance table.
+#[derive(Default)]
+struct Rows {
+    before: Vec<usize>,
+    prev: Vec<usize>,
+    cur: Vec<usize>,
+}
+
+impl Rows {
+    /// Edit distance counting insertions, deletions, substitutions and swaps
+    /// of adjacent characters (optimal string alignment). Gives up with
+    /// `limit + 1` once the distance must exceed `limit`.
+    fn distance(&mut self, a: &[char], b: &[char], limit: usize) -> usize {
+        self.before.clear();
+        self.before.resize(b.len() + 1, 0);
+        self.prev.clear();
+        self.prev.extend(0..=b.len());
+        self.cur.clear();
+        self.cur.resize(b.len() + 1, 0);
+
+        for i in 1..=a.len() {
+            self.cur[0] = i;
+            let mut row_min = i;
+            for j in 1..=b.len() {
+                let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+                let mut best = (self.prev[j] + 1)
+                    .min(self.cur[j - 1] + 1)
+                    .min(self.prev[j - 1] + cost);
+                if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                    best = best.min(self.before[j - 2] + 1);
+                }
+                self.cur[j] = best;
+                row_min = row_min.min(best);
+            }
+            // A swap can lower the next row by one below this one's minimum.
+            if row_min > limit + 1 {
+                return limit + 1;
+            }
+            mem::swap(&mut self.before, &mut self.prev);
+            mem::swap(&mut self.prev, &mut self.cur);
+        }
+        self.prev[b.len()]
+    }
+}
+
+/// How often each letter and digit occurs, with one bucket for the rest.
+fn histogram(chars: &[char]) -> [u8; 37] {
+    let mut counts = [0u8; 37];
+    for &c in chars {
+        let bucket = match c {
+            'a'..='z' => c as usize - 'a' as usize,
+            '0'..='9' => 26 + c as usize - '0' as usize,
+            _ => 36,
+        };
+        counts[bucket] = counts[bucket].saturating_add(1);
+    }
+    counts
+}
+
+struct Query {
+    text: String,
+    chars: Vec<char>,
+    histogram: [u8; 37],
+    /// At most 64 words, so that matches fit in a bit set.
+    words: Vec<Vec<char>>,
+}
+
+impl Query {
+    fn new(text: String) -> Query {
+        let chars: Vec<char> = text.chars().collect();
+        let histogram = histogram(&chars);
+        let words = text.split(' ')
+            .filter(|w| !STOP_WORDS.contains(w))
+            .take(64)
+            .map(|w| w.chars().collect())
+            .collect();
+        Query { text, chars, histogram, words }
+    }
+
+    /// 1.0 for an equal key, falling towards 0.0 as the edit distance grows.
+    fn similarity(&self, key: &[char], rows: &mut Rows) -> f32 {
+        let len = self.chars.len().max(key.len());
+        let score = |distance: usize| 1.0 - distance as f32 / len as f32;
+        // The largest distance that still scores enough to be a result.
+        let limit = (1..=len).take_while(|&d| score(d) >= MIN_SCORE).last().unwrap_or(0);
+        // Each edit adds or removes at most one character of each kind, so
+        // the distance is at least the surplus of either side.
+        let (mut surplus, mut deficit) = (0, 0);
+        for (&q, k) in self.histogram.iter().zip(histogram(key)) {
+            surplus += q.saturating_sub(k) as usize;
+            deficit += k.saturating_sub(q) as usize;
+        }
+        if surplus.max(deficit) > limit {
+            return 0.0;
+        }
+        match rows.distance(&self.chars, key, limit) {
+            distance if distance > limit => 0.0,
+            distance => score(distance),
+        }
+    }
+
+    /// Scores how many of the query's words appear in the key, allowing one
+    /// typo in words of five or more letters. Words in a different order
+    /// still match, so `"Korea South"` finds `"South Korea"`.
+    fn token_score(&self, key: &str, word: &mut Vec<char>, rows: &mut Rows) -> f32 {
+        let mut key_words = 0;
+        let mut matched = 0u64;
+        for w in key.split(' ').filter(|w| !STOP_WORDS.contains(w)) {
+            key_words += 1;
+            word.clear();
+            word.extend(w.chars());
+            for (i, q) in self.words.iter().enumerate() {
+                if matched & 1 << i != 0 {
+                    continue;
+                }
+                let found = match q.len() {
+                    0..=4 => q == word,
+                    len => len.abs_diff(word.len()) <= 1 && rows.distance(q, word, 1) <= 1,
+                };
+                if found {
+                    matched |= 1 << i;
+                }
+            }
+        }
+        if self.words.is_empty() || key_words == 0 {
+            return 0.0;
+        }
+
+        let matched = matched.count_ones() as f32;
+        // Matching every word of a longer key is weaker than matching all of it.
+        0.9 * matched / self.words.len() as f32 * (0.5 + 0.5 * (matched / key_words as f32).min(1.0))
+    }
+}
+
+pub(crate) fn search(query: &str) -> Vec<(Country, f32)> {
+    let query = normalize(query);
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let query = Query::new(query);
+    let mut rows = Rows::default();
+    let (mut key_chars, mut word) = (Vec::new(), Vec::new());
+    let mut best: CountryMap<f32> = CountryMap::new();
+    for &(key, country) in SEARCH_KEYS {
+        let score = if key == query.text {
+            1.0
+        } else {
+            key_chars.clear();
+            key_chars.extend(key.chars());
+            query.similarity(&key_chars, &mut rows)
+                .max(query.token_score(key, &mut word, &mut rows))
+                .min(0.99)
+        };
+        if score >= MIN_SCORE {
+            let best = best.entry(country).or_insert(score);
+            *best = best.max(score);
+        }
+    }
+
+    let mut results: Vec<(Country, f32)> = best.into_iter().collect();
+    results.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap().then(a.0.cmp(&b.0)));
+    results
+}
@@ -0,0 +1,408 @@
+use core::{ fmt, iter, ops, str };
+
+use error::CountryParseError;
+use lookup::{ country_at, slot, SLOTS };
+use Country;
+
+const WORDS: usize = SLOTS.div_ceil(64);
+
+/// A set of countries, stored as a fixed-size bitset with one bit per country.
+///
+/// Iteration is in alpha-2 order. A set never contains `Country::Unspecified`:
+/// inserting it does nothing, and `complement` is taken relative to
+/// `Country::ALL`.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct CountrySet {
+    bits: [u64; WORDS],
+}
+
+impl CountrySet {
+    pub const fn new() -> CountrySet {
+        CountrySet { bits: [0; WORDS] }
+    }
+
+    /// The set of every country in `Country::ALL`.
+    pub const fn all() -> CountrySet {
+        let mut bits = [!0; WORDS];
+        bits[0] &= !1;
+        bits[WORDS - 1] &= !0 >> (WORDS * 64 - SLOTS);
+        CountrySet { bits }
+    }
+
+    /// Builds a set in a constant expression:
+    ///
+    /// ```
+    /// # use iso_country::{ Country, CountrySet };
+    /// const BENELUX: CountrySet = CountrySet::from_slice(&[Country::BE, Country::NL, Country::LU]);
+    /// assert!(BENELUX.contains(Country::NL));
+    /// ```
+    pub const fn from_slice(countries: &[Country]) -> CountrySet {
+        let mut set = CountrySet::new();
+        let mut i = 0;
+        while i < countries.len() {
+            let slot = slot(countries[i]);
+            // Slot 0 is `Country::Unspecified`.
+            if slot != 0 {
+                set.bits[slot / 64] |= 1 << (slot % 64);
+            }
+            i += 1;
+        }
+        set
+    }
+
+    pub const fn contains(&self, country: Country) -> bool {
+        let slot = slot(country);
+        self.bits[slot / 64] & (1 << (slot % 64)) != 0
+    }
+
+    /// Adds a country, returning whether it was newly inserted.
+    /// `Country::Unspecified` is never inserted.
+    pub fn insert(&mut self, country: Country) -> bool {
+        let slot = slot(country);
+        if slot == 0 {
+            return false;
+        }
+        let present = self.contains(country);
+        self.bits[slot / 64] |= 1 << (slot % 64);
+        !present
+    }
+
+    /// Removes a country, returning whether it was present.
+    pub fn remove(&mut self, country: Country) -> bool {
+        let slot = slot(country);
+        let present = self.contains(country);
+        self.bits[slot / 64] &= !(1 << (slot % 64));
+        present
+    }
+
+    pub fn clear(&mut self) {
+        self.bits = [0; WORDS];
+    }
+
+    pub fn len(&self) -> usize {
+        self.bits.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.iter().all(|&w| w == 0)
+    }
+
+    pub const fn union(&self, other: &CountrySet) -> CountrySet {
+        let mut bits = self.bits;
+        let mut i = 0;
+        while i < WORDS {
+            bits[i] |= other.bits[i];
+            i += 1;
+        }
+        CountrySet { bits }
+    }
+
+    pub const fn intersection(&self, other: &CountrySet) -> CountrySet {
+        let mut bits = self.bits;
+        let mut i = 0;
+        while i < WORDS {
+            bits[i] &= other.bits[i];
+            i += 1;
+        }
+        CountrySet { bits }
+    }
+
+    pub const fn difference(&self, other: &CountrySet) -> CountrySet {
+        let mut bits = self.bits;
+        let mut i = 0;
+        while i < WORDS {
+            bits[i] &= !other.bits[i];
+            i += 1;
+        }
+        CountrySet { bits }
+    }
+
+    pub const fn symmetric_difference(&self, other: &CountrySet) -> CountrySet {
+        let mut bits = self.bits;
+        let mut i = 0;
+        while i < WORDS {
+            bits[i] ^= other.bits[i];
+            i += 1;
+        }
+        CountrySet { bits }
+    }
+
+    /// Every country in `Country::ALL` that is not in this set.
+    pub const fn complement(&self) -> CountrySet {
+        CountrySet::all().difference(self)
+    }
+
+    pub fn is_subset(&self, other: &CountrySet) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    pub fn is_superset(&self, other: &CountrySet) -> bool {
+        other.is_subset(self)
+    }
+
+    pub fn is_disjoint(&self, other: &CountrySet) -> bool {
+        self.intersection(other).is_empty()
+    }
+
+    pub fn iter(&self) -> Iter {
+        Iter { bits: self.bits, word: 0 }
+    }
+}
+
+impl fmt::Debug for CountrySet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Formats the set as comma-separated alpha-2 codes, e.g. `DE,FR,PL`.
+impl fmt::Display for CountrySet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (i, country) in self.iter().enumerate() {
+            if i > 0 {
+                f.write_str(",")?;
+            }
+            f.write_str(country.alpha2())?;
+        }
+        Ok(())
+    }
+}
+
+impl str::FromStr for CountrySet {
+    type Err = CountryParseError;
+
+    /// Parses a comma-separated list of codes such as `"PL, DE,FR"`. Blank
+    /// entries are skipped.
+    fn from_str(s: &str) -> Result<CountrySet, CountryParseError> {
+        s.split(',')
+            .map(str::trim)
+            .filter(|code| !code.is_empty())
+            .map(str::parse)
+            .collect()
+    }
+}
+
+macro_rules! set_op {
+    ($op:ident, $method:ident, $assign_op:ident, $assign_method:ident, $set_method:ident) => {
+        impl ops::$op for CountrySet {
+            type Output = CountrySet;
+
+            fn $method(self, other: CountrySet) -> CountrySet {
+                self.$set_method(&other)
+            }
+        }
+
+        impl ops::$assign_op for CountrySet {
+            fn $assign_method(&mut self, other: CountrySet) {
+                *self = self.$set_method(&other);
+            }
+        }
+    };
+}
+
+set_op!(BitOr, bitor, BitOrAssign, bitor_assign, union);
+set_op!(BitAnd, bitand, BitAndAssign, bitand_assign, intersection);
+set_op!(BitXor, bitxor, BitXorAssign, bitxor_assign, symmetric_difference);
+set_op!(Sub, sub, SubAssign, sub_assign, difference);
+
+impl ops::Not for CountrySet {
+    type Output = CountrySet;
+
+    fn not(self) -> CountrySet {
+        self.complement()
+    }
+}
+
+impl iter::FromIterator<Country> for CountrySet {
+    fn from_iter<I: IntoIterator<Item = Country>>(iter: I) -> CountrySet {
+        let mut set = CountrySet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Country> for CountrySet {
+    fn extend<I: IntoIterator<Item = Country>>(&mut self, iter: I) {
+        for country in iter {
+            self.insert(country);
+        }
+    }
+}
+
+impl IntoIterator for CountrySet {
+    type Item = Country;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &CountrySet {
+    type Item = Country;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        self.iter()
+    }
+}
+
+/// Iterator over the countries in a `CountrySet`, in alpha-2 order.
+#[derive(Clone, Debug)]
+pub struct Iter {
+    bits: [u64; WORDS],
+    word: usize,
+}
+
+impl Iterator for Iter {
+    type Item = Country;
+
+    fn next(&mut self) -> Option<Country> {
+        while self.word < WORDS {
+            let bits = self.bits[self.word];
+            if bits != 0 {
+                let bit = bits.trailing_zeros() as usize;
+                self.bits[self.word] &= bits - 1;
+                return Some(country_at(self.word * 64 + bit));
+            }
+            self.word += 1;
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits[self.word.min(WORDS)..].iter().map(|w| w.count_ones() as usize).sum();
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Iter {}
+
+impl iter::FusedIterator for Iter {}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use core::fmt;
+    use serde::{ Deserialize, Deserializer, Serialize, Serializer };
+    use serde::de::{ SeqAccess, Visitor };
+
+    use super::CountrySet;
+    use Country;
+
+    /// Serializes as a list of alpha-2 codes.
+    impl Serialize for CountrySet {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+            serializer.collect_seq(self.iter())
+        }
+    }
+
+    impl<'de> Deserialize<'de> for CountrySet {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+            struct SetVisitor;
+
+            impl<'de> Visitor<'de> for SetVisitor {
+                type Value = CountrySet;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    formatter.write_str("a list of country codes")
+                }
+
+                fn visit_seq<A>(self, mut access: A) -> Result<CountrySet, A::Error> where A: SeqAccess<'de> {
+                    let mut set = CountrySet::new();
+                    while let Some(country) = access.next_element::<Country>()? {
+                        set.insert(country);
+                    }
+                    Ok(set)
+                }
+            }
+
+            deserializer.deserialize_seq(SetVisitor)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate serde_json;
+    use std::string::ToString;
+    use std::vec::Vec;
+    use super::CountrySet;
+    use Country;
+
+    const EU_FOUNDERS: CountrySet = CountrySet::from_slice(&[
+        Country::BE, Country::DE, Country::FR, Country::IT, Country::LU, Country::NL,
+    ]);
+
+    #[test]
+    fn insert_contains_remove() {
+        let mut set = CountrySet::new();
+        assert!(set.is_empty());
+        assert!(set.insert(Country::PL));
+        assert!(!set.insert(Country::PL));
+        assert!(set.insert(Country::ZW));
+        assert!(set.contains(Country::PL));
+        assert!(!set.contains(Country::DE));
+        assert_eq!(2, set.len());
+        assert!(set.remove(Country::PL));
+        assert!(!set.remove(Country::PL));
+        assert_eq!(vec![Country::ZW], set.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn algebra() {
+        let benelux: CountrySet = "BE,NL,LU".parse().unwrap();
+        let visited: CountrySet = vec![Country::PL, Country::NL].into_iter().collect();
+
+        assert_eq!(6, EU_FOUNDERS.len());
+        assert!(benelux.is_subset(&EU_FOUNDERS));
+        assert!(EU_FOUNDERS.is_superset(&benelux));
+        assert_eq!("NL", (benelux & visited).to_string());
+        assert_eq!("BE,LU,NL,PL", (benelux | visited).to_string());
+        assert_eq!("DE,FR,IT", (EU_FOUNDERS - benelux).to_string());
+        assert_eq!("BE,LU,PL", (benelux ^ visited).to_string());
+        assert!((EU_FOUNDERS - benelux).is_disjoint(&benelux));
+
+        assert_eq!(Country::COUNT, CountrySet::all().len());
+        assert_eq!(Country::COUNT - 6, (!EU_FOUNDERS).len());
+        assert!(!CountrySet::new().complement().contains(Country::Unspecified));
+        assert_eq!(EU_FOUNDERS, !!EU_FOUNDERS);
+    }
+
+    #[test]
+    fn iterates_in_code_order() {
+        let all: Vec<_> = CountrySet::all().iter().collect();
+        assert_eq!(Country::ALL, &all[..]);
+        assert_eq!(Country::COUNT, CountrySet::all().iter().len());
+    }
+
+    #[test]
+    fn parse() {
+        let set: CountrySet = " PL, DE,FR ,".parse().unwrap();
+        assert_eq!("DE,FR,PL", set.to_string());
+        assert!("".parse::<CountrySet>().unwrap().is_empty());
+        assert!("PL,XX".parse::<CountrySet>().is_err());
+    }
+
+    #[test]
+    fn ignores_unspecified() {
+        let mut set = CountrySet::from_slice(&[Country::Unspecified, Country::PL]);
+        assert!(!set.insert(Country::Unspecified));
+        assert!(!set.contains(Country::Unspecified));
+        assert_eq!(1, set.len());
+        assert_eq!("PL", set.to_string());
+        assert_eq!(set, set.to_string().parse().unwrap());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let json = serde_json::to_string(&EU_FOUNDERS).unwrap();
+        assert_eq!(r#"["BE","DE","FR","IT","LU","NL"]"#, json);
+        assert_eq!(EU_FOUNDERS, serde_json::from_str::<CountrySet>(&json).unwrap());
+
+        let set: CountrySet = vec![Country::Unspecified, Country::PL].into_iter().collect();
+        let json = serde_json::to_string(&set).unwrap();
+        assert_eq!(r#"["PL"]"#, json);
+        assert_eq!(set, serde_json::from_str::<CountrySet>(&json).unwrap());
+    }
+}
@@ -0,0 +1,144 @@
+use core::{ fmt, str };
+
+use error::{ CountryParseError, ParseErrorKind };
+use { Country, REPLACEMENT_CODE_SEARCH_TABLE, RESERVED_CODE_SEARCH_TABLE };
+
+/// How ISO 3166-1 treats an alpha-2 code.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CodeStatus {
+    /// Officially assigned to a country.
+    Assigned(Country),
+    /// Reserved at the request of a national body, e.g. `EU` or `UK`.
+    ExceptionallyReserved,
+    /// Deleted, but reserved during a transitional period, e.g. `YU`.
+    TransitionallyReserved,
+    /// Reserved for use in other international standards, e.g. `RC`.
+    IndeterminatelyReserved,
+    /// Deleted and no longer reserved, e.g. `DD`.
+    FormerlyUsed,
+    /// Free for private use: `AA`, `QM` to `QZ`, `XA` to `XZ` and `ZZ`.
+    UserAssigned,
+    /// Not assigned and not reserved.
+    Unassigned,
+}
+
+impl CodeStatus {
+    pub fn is_assigned(self) -> bool {
+        matches!(self, CodeStatus::Assigned(_))
+    }
+
+    pub fn is_reserved(self) -> bool {
+        matches!(self, CodeStatus::ExceptionallyReserved
+                     | CodeStatus::TransitionallyReserved
+                     | CodeStatus::IndeterminatelyReserved)
+    }
+}
+
+fn is_user_assigned(code: &[u8]) -> bool {
+    matches!(*code, [b'A', b'A'] | [b'Z', b'Z'] | [b'Q', b'M'..=b'Z'] | [b'X', b'A'..=b'Z'])
+}
+
+fn reserved(code: &str) -> Option<(CodeStatus, &'static str)> {
+    RESERVED_CODE_SEARCH_TABLE.binary_search_by(|&(o, _, _)| o.cmp(code))
+        .ok()
+        .map(|pos| (RESERVED_CODE_SEARCH_TABLE[pos].1, RESERVED_CODE_SEARCH_TABLE[pos].2))
+}
+
+pub(crate) fn replacement(code: &str) -> Option<Country> {
+    REPLACEMENT_CODE_SEARCH_TABLE.binary_search_by(|&(o, _)| o.cmp(code))
+        .ok()
+        .map(|pos| REPLACEMENT_CODE_SEARCH_TABLE[pos].1)
+}
+
+/// Classifies an upper-case alpha-2 code.
+pub(crate) fn code_status(code: &str) -> CodeStatus {
+    if code.len() != 2 {
+        return CodeStatus::Unassigned;
+    }
+
+    if let Some(country) = Country::from_alpha2(code) {
+        return CodeStatus::Assigned(country);
+    }
+
+    if let Some((status, _)) = reserved(code) {
+        return status;
+    }
+
+    if is_user_assigned(code.as_bytes()) {
+        CodeStatus::UserAssigned
+    } else {
+        CodeStatus::Unassigned
+    }
+}
+
+/// Any two-letter code, including reserved and user-assigned ones such as
+/// `EU`, `UK` or `XK` that have no `Country` variant.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Alpha2Code([u8; 2]);
+
+impl Alpha2Code {
+    pub fn from_country(country: Country) -> Option<Alpha2Code> {
+        match country.alpha2().as_bytes() {
+            &[a, b] => Some(Alpha2Code([a, b])),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        str::from_utf8(&self.0).expect("alpha-2 codes are ASCII")
+    }
+
+    pub fn status(self) -> CodeStatus {
+        code_status(self.as_str())
+    }
+
+    /// The country this code is assigned to, if any.
+    pub fn country(self) -> Option<Country> {
+        match self.status() {
+            CodeStatus::Assigned(country) => Some(country),
+            _ => None,
+        }
+    }
+
+    /// The name of the country or entity this code stands for, e.g.
+    /// `"European Union"` for `EU` or `"Kosovo"` for `XK`.
+    #[cfg(feature = "names")]
+    pub fn name(self) -> Option<&'static str> {
+        self.country()
+            .map(Country::name)
+            .or_else(|| reserved(self.as_str()).map(|(_, name)| name))
+    }
+
+    /// Maps codes commonly used in place of an assigned one to that code,
+    /// e.g. `UK` to `GB` and `EL` to `GR`. Other codes are returned as is.
+    pub fn normalize(self) -> Alpha2Code {
+        replacement(self.as_str())
+            .and_then(Alpha2Code::from_country)
+            .unwrap_or(self)
+    }
+}
+
+impl str::FromStr for Alpha2Code {
+    type Err = CountryParseError;
+
+    /// Accepts any two ASCII letters, in either case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let b = s.as_bytes();
+        if b.len() == 2 && b.iter().all(|b| b.is_ascii_alphabetic()) {
+            return Ok(Alpha2Code([b[0].to_ascii_uppercase(), b[1].to_ascii_uppercase()]));
+        }
+
+        let err = CountryParseError::new(s);
+        match err.kind() {
+            ParseErrorKind::Empty | ParseErrorKind::NonAscii => Err(err),
+            _ if s.len() != 2 => Err(CountryParseError::with_kind(ParseErrorKind::InvalidLength, s)),
+            _ => Err(CountryParseError::with_kind(ParseErrorKind::InvalidCharacter, s)),
+        }
+    }
+}
+
+impl fmt::Display for Alpha2Code {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
@@ -0,0 +1,311 @@
+//! Alternative serde representations of `Country`, for use with
+//! `#[serde(with = "...")]`.
+
+/// Like the default representation, but an empty string is an error rather
+/// than `Country::Unspecified`.
+///
+/// ```ignore
+/// #[serde(with = "iso_country::with::strict")]
+/// country: Country,
+/// ```
+pub mod strict {
+    use core::fmt;
+    use serde::{ de, ser, Deserializer, Serialize, Serializer };
+    use Country;
+
+    pub fn serialize<S>(country: &Country, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        match *country {
+            Country::Unspecified => Err(ser::Error::custom("cannot serialize an unspecified country")),
+            country => country.serialize(serializer),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Country, D::Error> where D: Deserializer<'de> {
+        deserializer.deserialize_str(StrictVisitor)
+    }
+
+    pub(super) struct StrictVisitor;
+
+    impl<'de> de::Visitor<'de> for StrictVisitor {
+        type Value = Country;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a non-empty alpha-2, alpha-3 or numeric country code")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Country, E> where E: de::Error {
+            Country::parse_strict(value)
+                .map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+        }
+    }
+}
+
+/// For `Option<Country>`: `null`, a missing value or an empty string
+/// deserialize to `None`, and `None` serializes as `null`.
+///
+/// ```ignore
+/// #[serde(default, with = "iso_country::with::optional")]
+/// country: Option<Country>,
+/// ```
+pub mod optional {
+    use core::fmt;
+    use serde::{ de, Deserializer, Serialize, Serializer };
+    use serde::de::Visitor;
+    use Country;
+    use super::strict::StrictVisitor;
+
+    pub fn serialize<S>(country: &Option<Country>, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        match *country {
+            None | Some(Country::Unspecified) => serializer.serialize_none(),
+            Some(ref country) => country.serialize(serializer),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<Country>, D::Error> where D: Deserializer<'de> {
+        deserializer.deserialize_option(OptionalVisitor)
+    }
+
+    struct OptionalVisitor;
+
+    impl<'de> Visitor<'de> for OptionalVisitor {
+        type Value = Option<Country>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("an alpha-2, alpha-3 or numeric country code, an empty string or null")
+        }
+
+        fn visit_none<E>(self) -> Result<Option<Country>, E> where E: de::Error {
+            Ok(None)
+        }
+
+        fn visit_unit<E>(self) -> Result<Option<Country>, E> where E: de::Error {
+            Ok(None)
+        }
+
+        fn visit_some<D>(self, deserializer: D) -> Result<Option<Country>, D::Error> where D: Deserializer<'de> {
+            deserializer.deserialize_str(self)
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Option<Country>, E> where E: de::Error {
+            match value {
+                "" => Ok(None),
+                _ => StrictVisitor.visit_str(value).map(Some),
+            }
+        }
+    }
+}
+
+/// As the alpha-3 code, e.g. `"POL"`. `Country::Unspecified` is `""`.
+#[cfg(feature = "alpha3")]
+pub mod alpha3 {
+    use core::fmt;
+    use serde::{ de, Deserializer, Serializer };
+    use Country;
+
+    pub fn serialize<S>(country: &Country, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        serializer.serialize_str(country.alpha3())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Country, D::Error> where D: Deserializer<'de> {
+        deserializer.deserialize_str(Alpha3Visitor)
+    }
+
+    struct Alpha3Visitor;
+
+    impl<'de> de::Visitor<'de> for Alpha3Visitor {
+        type Value = Country;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a 3 letter country code")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Country, E> where E: de::Error {
+            match value {
+                "" => Ok(Country::Unspecified),
+                _ => Country::from_alpha3(value)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self)),
+            }
+        }
+    }
+}
+
+/// As the numeric code in an integer, e.g. `616`. `Country::Unspecified` is `0`.
+#[cfg(feature = "numeric")]
+pub mod numeric {
+    use core::fmt;
+    use serde::{ de, Deserializer, Serializer };
+    use Country;
+
+    pub fn serialize<S>(country: &Country, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        serializer.serialize_u16(country.numeric())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Country, D::Error> where D: Deserializer<'de> {
+        deserializer.deserialize_u16(NumericVisitor)
+    }
+
+    pub(super) struct NumericVisitor;
+
+    impl<'de> de::Visitor<'de> for NumericVisitor {
+        type Value = Country;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a numeric country code")
+        }
+
+        fn visit_u64<E>(self, value: u64) -> Result<Country, E> where E: de::Error {
+            match value {
+                0 => Ok(Country::Unspecified),
+                _ => Country::from_numeric(value as u16)
+                    .filter(|_| value <= u64::from(u16::MAX))
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(value), &self)),
+            }
+        }
+
+        fn visit_i64<E>(self, value: i64) -> Result<Country, E> where E: de::Error {
+            if value < 0 {
+                return Err(E::invalid_value(de::Unexpected::Signed(value), &self));
+            }
+            self.visit_u64(value as u64)
+        }
+    }
+}
+
+/// As the zero-padded numeric code in a string, e.g. `"004"`.
+/// `Country::Unspecified` is `""`.
+#[cfg(feature = "numeric")]
+pub mod numeric_str {
+    use core::fmt;
+    use serde::{ de, Deserializer, Serializer };
+    use Country;
+
+    pub fn serialize<S>(country: &Country, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        serializer.serialize_str(country.numeric_str())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Country, D::Error> where D: Deserializer<'de> {
+        deserializer.deserialize_str(NumericStrVisitor)
+    }
+
+    struct NumericStrVisitor;
+
+    impl<'de> de::Visitor<'de> for NumericStrVisitor {
+        type Value = Country;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a 3 digit country code")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Country, E> where E: de::Error {
+            match value {
+                "" => Ok(Country::Unspecified),
+                _ => Country::from_numeric_str(value)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self)),
+            }
+        }
+    }
+}
+
+/// As the English short name, e.g. `"Poland"`. `Country::Unspecified` is `""`.
+#[cfg(feature = "names")]
+pub mod name {
+    use core::fmt;
+    use serde::{ de, Deserializer, Serializer };
+    use Country;
+
+    pub fn serialize<S>(country: &Country, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        serializer.serialize_str(country.name())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Country, D::Error> where D: Deserializer<'de> {
+        deserializer.deserialize_str(NameVisitor)
+    }
+
+    struct NameVisitor;
+
+    impl<'de> de::Visitor<'de> for NameVisitor {
+        type Value = Country;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("a country name")
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Country, E> where E: de::Error {
+            match value {
+                "" => Ok(Country::Unspecified),
+                _ => Country::from_name(value)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self)),
+            }
+        }
+    }
+}
+
+/// Accepts any form of code: alpha-2 or alpha-3 in either case, numeric
+/// codes as strings or integers, and surrounding whitespace. Serializes as
+/// alpha-2, like the default representation.
+///
+/// ```ignore
+/// #[serde(with = "iso_country::with::lenient")]
+/// country: Country,
+/// ```
+pub mod lenient {
+    use core::fmt;
+    use serde::{ de, Deserializer, Serialize, Serializer };
+    use error::CountryParseError;
+    use Country;
+
+    pub fn serialize<S>(country: &Country, serializer: S) -> Result<S::Ok, S::Error> where S: Serializer {
+        country.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Country, D::Error> where D: Deserializer<'de> {
+        deserializer.deserialize_any(LenientVisitor)
+    }
+
+    struct LenientVisitor;
+
+    impl<'de> de::Visitor<'de> for LenientVisitor {
+        type Value = Country;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("an alpha-2, alpha-3 or numeric country code")
+        }
+
+        #[cfg(feature = "numeric")]
+        fn visit_u64<E>(self, value: u64) -> Result<Country, E> where E: de::Error {
+            super::numeric::NumericVisitor.visit_u64(value)
+        }
+
+        #[cfg(feature = "numeric")]
+        fn visit_i64<E>(self, value: i64) -> Result<Country, E> where E: de::Error {
+            super::numeric::NumericVisitor.visit_i64(value)
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Country, E> where E: de::Error {
+            let code = value.trim();
+            if code.is_empty() {
+                return Ok(Country::Unspecified);
+            }
+
+            #[cfg(feature = "numeric")]
+            if code.len() < 3 && code.bytes().all(|b| b.is_ascii_digit()) {
+                let n = code.parse().expect("at most 2 digits");
+                return Country::from_numeric(n)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &self));
+            }
+
+            Country::parse_lenient(code)
+                .map_err(|err| E::invalid_value(de::Unexpected::Str(value), &Expected(err)))
+        }
+    }
+
+    /// The visitor's expectation, followed by the codes the input resembles.
+    struct Expected(CountryParseError);
+
+    impl de::Expected for Expected {
+        fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("an alpha-2, alpha-3 or numeric country code")?;
+            self.0.fmt_suggestions(formatter)
+        }
+    }
+}